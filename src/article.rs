@@ -66,8 +66,25 @@ pub struct Article {
     /// data is checked first, followed by OpenGraph and Twitter Card meta tags. If neither is
     /// available, the `<title>` tag is used after cleaning it of the site name. As a final
     /// fallback, the first `<h1>` tag in the document is used.
+    ///
+    /// When that title is just the site name, or a `<title>` like "Heading | Site", the
+    /// `<h1>`/`<h2>` inside or just above the extracted content is preferred. See
+    /// [`strip_title_branding`](crate::ReadabilityOptions::strip_title_branding) and
+    /// [`Article::title_source`].
     pub title: Option<String>,
 
+    /// Where [`Article::title`] came from.
+    pub title_source: Option<TitleSource>,
+
+    /// Subtitle, standfirst or dek shown below the headline.
+    ///
+    /// Taken from JSON-LD or microdata `alternativeHeadline`, then elements with classes
+    /// like "standfirst", "dek" or "subtitle", then an `<h2>` directly following the
+    /// `<h1>`. It stays in [`Article::content`] unless
+    /// [`remove_subtitle_from_content`](crate::ReadabilityOptions::remove_subtitle_from_content)
+    /// is set.
+    pub subtitle: Option<String>,
+
     /// Cleaned HTML content of the article.
     ///
     /// This contains the main article content with:
@@ -106,6 +123,16 @@ pub struct Article {
     /// separated by commas.
     pub byline: Option<String>,
 
+    /// Where [`Article::byline`] was found.
+    pub byline_source: Option<BylineSource>,
+
+    /// How likely [`Article::byline`] is to be an actual author credit.
+    ///
+    /// Structured data and explicit author markup are [`BylineConfidence::High`]; text that
+    /// merely looks like a byline is [`BylineConfidence::Low`], and may be junk such as
+    /// "Updated 5 min ago".
+    pub byline_confidence: Option<BylineConfidence>,
+
     /// The image is extracted from various sources, checking JSON-LD structured data first
     /// (supporting simple URLs, ImageObject with `url` property, and arrays of images).
     /// If not found, Open Graph meta tags are checked in priority order: `og:image:secure_url`,
@@ -113,11 +140,18 @@ pub struct Article {
     /// `thumbnail` or `image` meta tags are examined next. Finally, elements with
     /// `link[rel="image_src"]` or `itemprop="image"` attributes are checked as fallbacks.
     /// The first valid URL encountered in this priority order is used.
+    ///
+    /// When a URL is passed to [`Readability::new`](crate::Readability::new), this and the
+    /// other URL-valued fields are resolved against it (and the document's `<base href>`);
+    /// values that are not valid URLs are dropped.
     pub image: Option<String>,
 
     /// Text direction hint: "ltr" (left-to-right), "rtl" (right-to-left), or "auto".
     ///
-    /// Extracted from the `dir` attribute on the `<html>` element.
+    /// Taken from the `dir` attribute of the extracted content's closest ancestor that has one
+    /// (falling back to `<html>` and `<body>`). Without one, it is "rtl" when the first strong
+    /// directional character of the text is right-to-left, and None otherwise. Right-to-left
+    /// blocks in left-to-right content are given their own `dir` attribute.
     pub dir: Option<String>,
 
     /// Name of the website or publication.
@@ -126,12 +160,25 @@ pub struct Article {
     /// publisher name field.
     pub site_name: Option<String>,
 
+    /// The organization publishing the article.
+    ///
+    /// Gathers the JSON-LD or microdata `publisher`, the site name, app name meta tags and
+    /// social accounts. `None` when the page names no publisher at all.
+    pub publisher: Option<Publisher>,
+
     /// Language code of the content (e.g., "en", "es", "fr").
     ///
     /// Extracted from the `lang` attribute on the `<html>` element or
     /// `Content-Language` meta tag.
     pub lang: Option<String>,
 
+    /// Language identified from the extracted text, with a confidence score.
+    ///
+    /// Unlike `lang`, which is whatever the page declares (often a template default), this
+    /// is detected statistically from `text_content`. Only populated when the `lang-detect`
+    /// cargo feature is enabled; always `None` otherwise.
+    pub detected_lang: Option<DetectedLanguage>,
+
     /// Publication or modification timestamp.
     ///
     /// The publication time is extracted from the JSON-LD `datePublished` field or the
@@ -144,6 +191,361 @@ pub struct Article {
     /// This is the extracted content before the final cleaning steps,
     /// useful for debugging or custom post-processing.
     pub raw_content: Option<String>,
+
+    /// Whether the article is freely readable or sits behind a paywall.
+    ///
+    /// Publisher markup is checked first: the JSON-LD (or microdata) `isAccessibleForFree`
+    /// flag and `hasPart` sections with a `cssSelector` that are not free, then the
+    /// `article:content_tier` meta tag, which also distinguishes metered paywalls. Without
+    /// markup, a short extract sitting next to a paywall element (e.g. `class="paywall"`)
+    /// is reported as [`ArticleAccess::SubscriberOnly`], meaning `content` is likely only a
+    /// teaser of the full article.
+    pub access: ArticleAccess,
+
+    /// Candidate lead images, in priority order.
+    ///
+    /// Metadata images come first, in the same order used to pick [`Article::image`]: JSON-LD,
+    /// Open Graph, Twitter Card, microdata/RDFa and finally `link[rel="image_src"]` or
+    /// `itemprop="image"`. Images inside the extracted content follow in document order.
+    /// URLs are resolved against the base URL and duplicates are merged, keeping the first
+    /// source and filling in dimensions, alt text and caption from later occurrences.
+    pub images: Vec<ImageInfo>,
+
+    /// Canonical URL of the article.
+    ///
+    /// Taken from `link[rel="canonical"]`, falling back to the `og:url` meta tag.
+    pub canonical_url: Option<String>,
+
+    /// Author profile links.
+    ///
+    /// Taken from the JSON-LD author `url`, an `article:author` meta tag holding a URL,
+    /// the microdata/RDFa author `url`, or `rel="author"` links, in that priority order.
+    pub author_urls: Vec<String>,
+
+    /// URL of the site's favicon.
+    ///
+    /// The first `link[rel~="icon"]` in the document, falling back to `/favicon.ico` on the
+    /// base URL when one was passed to [`Readability::new`](crate::Readability::new).
+    pub favicon: Option<String>,
+
+    /// Every icon the site declares, in document order.
+    ///
+    /// Collected from `link[rel~="icon"]`, `apple-touch-icon` and `mask-icon` links (with
+    /// their `sizes`), followed by the JSON-LD or microdata `publisher.logo` and the default
+    /// `/favicon.ico` when a base URL is known.
+    pub site_icons: Vec<SiteIcon>,
+
+    /// Comment count, thread link and comment system, when the page has a discussion.
+    ///
+    /// The count and link come from JSON-LD or microdata (`commentCount`, a `CommentAction`
+    /// `interactionStatistic`, `discussionUrl`), then from comment-count elements and
+    /// `#comments` links on the page. The comments themselves are never part of `content`.
+    pub discussion: Option<Discussion>,
+
+    /// URL of the next page when the article is split over several pages.
+    ///
+    /// Taken from `rel="next"`, "Next" links in pagination blocks or the link numbered
+    /// one past the current page, and resolved against the base URL when one is given.
+    /// Fetch it yourself, parse it and combine the results with
+    /// [`merge_pages`](crate::merge_pages).
+    pub next_page_url: Option<String>,
+
+    /// Confidence that `content` is the article body, from 0.0 to 1.0.
+    ///
+    /// Combines how far the chosen element outscored the others, how many scoring
+    /// passes had to be relaxed, the link density of the content and whether it came
+    /// from an `<article>` or `<main>` element, scaled down for text shorter than the
+    /// character threshold. Low values (below about 0.5) usually mean a navigation page,
+    /// a teaser or the wrong block; consider showing the original page instead.
+    pub confidence: f32,
+}
+
+/// Document metadata without the article content, see
+/// [`Readability::metadata`](crate::Readability::metadata).
+///
+/// Fields are filled the same way as their [`Article`] counterparts, except that
+/// nothing is derived from the extracted content: there is no heading to refine the
+/// title, no excerpt from the first paragraph, no content images and no paywall
+/// detection.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct ArticleMetadata {
+    /// See [`Article::title`].
+    pub title: Option<String>,
+    /// See [`Article::title_source`].
+    pub title_source: Option<TitleSource>,
+    /// See [`Article::subtitle`].
+    pub subtitle: Option<String>,
+    /// See [`Article::excerpt`]; only the metadata description is used.
+    pub excerpt: Option<String>,
+    /// See [`Article::byline`].
+    pub byline: Option<String>,
+    /// See [`Article::byline_source`].
+    pub byline_source: Option<BylineSource>,
+    /// See [`Article::byline_confidence`].
+    pub byline_confidence: Option<BylineConfidence>,
+    /// See [`Article::image`].
+    pub image: Option<String>,
+    /// See [`Article::dir`]; only the document's declared direction is used.
+    pub dir: Option<String>,
+    /// See [`Article::site_name`].
+    pub site_name: Option<String>,
+    /// See [`Article::publisher`].
+    pub publisher: Option<Publisher>,
+    /// See [`Article::lang`].
+    pub lang: Option<String>,
+    /// See [`Article::published_time`].
+    pub published_time: Option<String>,
+    /// See [`Article::access`]; only structured data and meta tags are used.
+    pub access: ArticleAccess,
+    /// See [`Article::images`]; images inside the content are not included.
+    pub images: Vec<ImageInfo>,
+    /// See [`Article::canonical_url`].
+    pub canonical_url: Option<String>,
+    /// See [`Article::author_urls`].
+    pub author_urls: Vec<String>,
+    /// See [`Article::favicon`].
+    pub favicon: Option<String>,
+    /// See [`Article::site_icons`].
+    pub site_icons: Vec<SiteIcon>,
+    /// See [`Article::discussion`].
+    pub discussion: Option<Discussion>,
+    /// See [`Article::next_page_url`].
+    pub next_page_url: Option<String>,
+}
+
+/// An alternative article body, see
+/// [`Readability::parse_candidates`](crate::Readability::parse_candidates).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ArticleCandidate {
+    /// Cleaned HTML, processed like [`Article::content`].
+    pub content: String,
+    /// Plain text of `content`.
+    pub text_content: String,
+    /// Length of `text_content` in bytes.
+    pub length: usize,
+    /// Content score of the element the extraction started from.
+    pub score: f64,
+    /// Between 0.0 and 1.0: the score relative to the highest-scoring candidate,
+    /// reduced for text shorter than the character threshold.
+    pub relative_score: f64,
+    /// Scoring passes active when the candidate was found.
+    pub flags: ExtractionFlags,
+}
+
+/// Scoring passes used for an extraction attempt, see [`ArticleCandidate::flags`].
+///
+/// The first attempt runs with all of them; each retry turns one more off.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ExtractionFlags {
+    /// Elements whose class or id looks like boilerplate were skipped.
+    pub strip_unlikelys: bool,
+    /// Class and id names added to or subtracted from scores.
+    pub weight_classes: bool,
+    /// Low-quality blocks were removed conditionally.
+    pub clean_conditionally: bool,
+}
+
+/// A language identified from the article text, see [`Article::detected_lang`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DetectedLanguage {
+    /// ISO 639-1 code (e.g. "en"), comparable with the declared `lang`.
+    pub code: String,
+    /// Confidence between 0.0 and 1.0.
+    pub confidence: f64,
+    /// Whether the detector considers the result reliable.
+    pub reliable: bool,
+}
+
+/// An image found in the page metadata or the article content, see [`Article::images`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ImageInfo {
+    /// Image URL, absolute when a base URL is available.
+    pub url: String,
+    /// Width in pixels from `og:image:width`, the JSON-LD `ImageObject` or the `<img>` attribute.
+    pub width: Option<u32>,
+    /// Height in pixels from `og:image:height`, the JSON-LD `ImageObject` or the `<img>` attribute.
+    pub height: Option<u32>,
+    /// Alternative text from the `alt` attribute or `og:image:alt`/`twitter:image:alt`.
+    pub alt: Option<String>,
+    /// Caption from the enclosing `<figure>`'s `<figcaption>` or the JSON-LD `caption`.
+    pub caption: Option<String>,
+    /// Where the image was found.
+    pub source: ImageSource,
+}
+
+/// An icon or logo representing the site, see [`Article::site_icons`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SiteIcon {
+    /// Icon URL, absolute when a base URL is available.
+    pub url: String,
+    /// Width in pixels of the largest size listed in `sizes`, or from the logo `ImageObject`.
+    pub width: Option<u32>,
+    /// Height in pixels of the largest size listed in `sizes`, or from the logo `ImageObject`.
+    pub height: Option<u32>,
+    /// What kind of icon this is.
+    pub kind: SiteIconKind,
+}
+
+/// The publishing organization, see [`Article::publisher`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Publisher {
+    /// Publisher name: the site name, else `application-name` or
+    /// `apple-mobile-web-app-title`.
+    pub name: Option<String>,
+    /// Other names the publisher goes by on the page, e.g. a JSON-LD publisher name that
+    /// differs from `og:site_name`.
+    pub aliases: Vec<String>,
+    /// Publisher home page from the Schema.org `publisher.url`.
+    pub url: Option<String>,
+    /// Logo URL from the Schema.org `publisher.logo`.
+    pub logo: Option<String>,
+    /// Twitter/X account from `twitter:site`, as an `@handle`.
+    pub twitter_handle: Option<String>,
+    /// Facebook page IDs from `fb:pages`.
+    pub facebook_pages: Vec<String>,
+}
+
+/// Engagement information about an article's comments, see [`Article::discussion`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Discussion {
+    /// Number of comments, when the page states it.
+    pub comment_count: Option<u64>,
+    /// Link to the comment thread, absolute when a base URL is available.
+    pub url: Option<String>,
+    /// The comment system embedded in the page.
+    pub system: Option<CommentSystem>,
+}
+
+/// The comment system of a [`Discussion`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum CommentSystem {
+    /// Disqus (`#disqus_thread` or the Disqus embed script).
+    Disqus,
+    /// The Coral Project comment stream (`#coral_thread` or `Coral.createStreamEmbed`).
+    Coral,
+    /// The site's own comment section, such as WordPress comments.
+    Native,
+}
+
+/// The kind of a [`SiteIcon`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum SiteIconKind {
+    /// `link[rel~="icon"]`, including `shortcut icon`.
+    Icon,
+    /// `link[rel="apple-touch-icon"]` or `apple-touch-icon-precomposed`.
+    AppleTouchIcon,
+    /// `link[rel="mask-icon"]`, a monochrome SVG used for pinned tabs.
+    MaskIcon,
+    /// The Schema.org `publisher.logo`.
+    PublisherLogo,
+    /// The conventional `/favicon.ico`, not declared by the page.
+    DefaultFavicon,
+}
+
+/// Where an [`ImageInfo`] was found.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum ImageSource {
+    /// JSON-LD `image` or `thumbnailUrl`.
+    JsonLd,
+    /// `og:image` meta tags.
+    OpenGraph,
+    /// `twitter:image` meta tags.
+    TwitterCard,
+    /// Schema.org microdata or RDFa `image` property.
+    Microdata,
+    /// `link[rel="image_src"]` or an `itemprop="image"` element.
+    Document,
+    /// An `<img>` inside the extracted article content.
+    Content,
+}
+
+/// Where the article title was found, see [`Article::title_source`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum TitleSource {
+    /// JSON-LD `name` or `headline`.
+    JsonLd,
+    /// Open Graph, Twitter Card, Dublin Core or other title meta tags.
+    Meta,
+    /// The document `<title>`, after separator heuristics.
+    Document,
+    /// Schema.org microdata or RDFa `name`/`headline`.
+    Microdata,
+    /// The `<h1>` or `<h2>` heading of the extracted article.
+    Heading,
+}
+
+/// Where the byline was found, see [`Article::byline_source`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum BylineSource {
+    /// JSON-LD `author`.
+    JsonLd,
+    /// Author meta tags (`author`, `dc:creator`, `article:author`, ...).
+    Meta,
+    /// Schema.org microdata or RDFa `author`.
+    Microdata,
+    /// A `rel="author"` link.
+    RelAuthor,
+    /// An `itemprop="author"` element.
+    Itemprop,
+    /// An element with a byline or author class or id.
+    Class,
+    /// An all-caps author credit in the article standfirst.
+    Standfirst,
+    /// An `<address>` element.
+    Address,
+    /// A paragraph or span whose text reads like a byline ("By ...").
+    Text,
+}
+
+/// Confidence in the extracted byline, see [`Article::byline_confidence`].
+///
+/// Ordered from `Low` to `High`, so `confidence >= BylineConfidence::Medium` works.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "kebab-case")]
+pub enum BylineConfidence {
+    /// Found only by the shape of its text.
+    Low,
+    /// Found in byline-like markup or author meta tags.
+    Medium,
+    /// Found in structured data or explicit author markup.
+    High,
+}
+
+/// Access restriction of an article, see [`Article::access`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum ArticleAccess {
+    /// The article is free to read.
+    Free,
+    /// A limited number of articles can be read before a subscription is required.
+    Metered,
+    /// The full article requires a subscription or login.
+    SubscriberOnly,
+    /// No access information was found.
+    #[default]
+    Unknown,
+}
+
+impl ArticleAccess {
+    /// Returns `true` if the article is (or may be) behind a paywall.
+    pub fn is_restricted(&self) -> bool {
+        matches!(self, Self::Metered | Self::SubscriberOnly)
+    }
+}
+
+impl Publisher {
+    /// The publisher name followed by its aliases.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.name
+            .iter()
+            .chain(self.aliases.iter())
+            .map(String::as_str)
+    }
 }
 
 impl Article {
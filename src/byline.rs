@@ -0,0 +1,358 @@
+//! Byline and author-name heuristics, usable without parsing a whole article.
+//!
+//! These are the same checks [`Readability`](crate::Readability) runs on the
+//! byline it extracts, exposed for author strings from other sources such as
+//! RSS `<author>` fields or CMS exports.
+//!
+//! ```rust
+//! use readabilityrs::byline::{clean_byline, BylineContext, BylineReason};
+//!
+//! let result = clean_byline(
+//!     "By Jane Smith, Senior Reporter\n@janesmith\n5 min ago",
+//!     &BylineContext::default(),
+//! );
+//! assert_eq!(result.reason, BylineReason::Accepted);
+//! assert_eq!(result.names, vec!["Jane Smith"]);
+//! ```
+
+use crate::utils;
+use serde::{Deserialize, Serialize};
+
+pub use crate::utils::{
+    is_byline_redundant_with_site_name, looks_like_author_name, looks_like_org_credit,
+};
+
+/// What is known about where a byline appeared, see [`clean_byline`].
+#[derive(Debug, Clone, Default)]
+pub struct BylineContext {
+    /// Language of the page or feed (e.g. `"de"` or `"pt-BR"`), enabling localized
+    /// prefixes such as "Von" and localized dates.
+    pub lang: Option<String>,
+    /// Site or publisher names; a byline that only repeats one of them is dropped.
+    pub site_names: Vec<String>,
+}
+
+/// The outcome of [`clean_byline`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BylineResult {
+    /// The cleaned byline, as [`Article::byline`](crate::Article::byline) would show it.
+    ///
+    /// `None` unless `reason` is [`BylineReason::Accepted`].
+    pub byline: Option<String>,
+    /// Individual author names, without prefixes, job titles or organizations.
+    pub names: Vec<String>,
+    /// Fragments of the input left out of `names`, in the order they were removed.
+    pub removed: Vec<RemovedFragment>,
+    /// Why the byline was kept or dropped.
+    pub reason: BylineReason,
+}
+
+/// Why [`clean_byline`] kept or dropped a byline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BylineReason {
+    /// The byline names at least one author.
+    Accepted,
+    /// Nothing but whitespace, dates or prefixes was left.
+    Empty,
+    /// The byline credits an organization, such as a news agency or a desk.
+    OrgCredit,
+    /// The byline repeats one of the [`BylineContext::site_names`].
+    SiteName,
+    /// The text is not a byline, e.g. a navigation menu or only a social handle.
+    NotAByline,
+}
+
+/// A piece of the input dropped from the names, see [`BylineResult::removed`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemovedFragment {
+    /// The removed text, trimmed of surrounding separators.
+    pub text: String,
+    /// What the removed text was.
+    pub kind: FragmentKind,
+}
+
+/// The kind of a [`RemovedFragment`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FragmentKind {
+    /// A date, time or "updated" clause.
+    Date,
+    /// A social media handle or "Follow @..." line.
+    SocialHandle,
+    /// A job title such as "Senior Reporter".
+    JobTitle,
+    /// An organization credited next to the authors.
+    Organization,
+    /// A credit prefix such as "By", "Von" or "文/".
+    Prefix,
+}
+
+impl RemovedFragment {
+    pub(crate) fn new(text: &str, kind: FragmentKind) -> Self {
+        let text = text
+            .trim()
+            .trim_matches(['-', '–', '—', '|', '•', '·', ':', ';', ',', '，'])
+            .trim();
+        Self {
+            text: text.to_string(),
+            kind,
+        }
+    }
+}
+
+/// Clean a raw byline and split it into author names
+///
+/// Applies the byline cleaning used for [`Article::byline`](crate::Article::byline):
+/// dates, relative timestamps and social handles are dropped, organization
+/// credits and menus are rejected, and with [`BylineContext::lang`] set,
+/// localized prefixes and dates are removed too. The result is then split into
+/// names on commas and conjunctions, leaving out job titles and organizations.
+pub fn clean_byline(text: &str, context: &BylineContext) -> BylineResult {
+    let lang = context.lang.as_deref();
+    let mut removed = Vec::new();
+    let rejected = |reason, removed| BylineResult {
+        byline: None,
+        names: Vec::new(),
+        removed,
+        reason,
+    };
+
+    let text = utils::unescape_html_entities(text);
+    let cleaned = match utils::clean_byline_text_tracked(&text, &mut removed) {
+        utils::CleanBylineOutcome::Accepted(cleaned) => cleaned,
+        utils::CleanBylineOutcome::DroppedOrgCredit => {
+            return rejected(BylineReason::OrgCredit, removed)
+        }
+        utils::CleanBylineOutcome::Dropped if text.trim().is_empty() => {
+            return rejected(BylineReason::Empty, removed)
+        }
+        utils::CleanBylineOutcome::Dropped => return rejected(BylineReason::NotAByline, removed),
+    };
+    let Some(cleaned) = utils::clean_localized_byline_tracked(&cleaned, lang, &mut removed) else {
+        return rejected(BylineReason::Empty, removed);
+    };
+
+    if context
+        .site_names
+        .iter()
+        .any(|site_name| utils::is_byline_redundant_with_site_name(&cleaned, site_name))
+    {
+        return rejected(BylineReason::SiteName, removed);
+    }
+
+    let split_from = removed.len();
+    let names = split_names(&cleaned, lang, &mut removed);
+    if names.is_empty() {
+        let org_credit = removed[split_from..]
+            .iter()
+            .any(|fragment| fragment.kind == FragmentKind::Organization);
+        let reason = if org_credit {
+            BylineReason::OrgCredit
+        } else {
+            BylineReason::Empty
+        };
+        return rejected(reason, removed);
+    }
+
+    BylineResult {
+        byline: Some(cleaned),
+        names,
+        removed,
+        reason: BylineReason::Accepted,
+    }
+}
+
+/// Split a cleaned byline into names on lines, commas and conjunctions
+fn split_names(
+    byline: &str,
+    lang: Option<&str>,
+    removed: &mut Vec<RemovedFragment>,
+) -> Vec<String> {
+    let conjunctions: &[&str] = match lang
+        .and_then(|lang| lang.split(['-', '_']).next())
+        .map(str::to_ascii_lowercase)
+        .as_deref()
+    {
+        Some("de") => &[" und "],
+        Some("fr") => &[" et "],
+        Some("es") => &[" y ", " e "],
+        Some("pt") => &[" e "],
+        Some("it") => &[" e ", " ed "],
+        Some("nl") => &[" en "],
+        Some("ru") => &[" и "],
+        _ => &[],
+    };
+
+    let mut names = Vec::new();
+    for line in byline.split('\n') {
+        let mut line = line.trim();
+        if let Some(prefix) = utils::BY_PREFIX_REGEX.find(line) {
+            removed.push(RemovedFragment::new(prefix.as_str(), FragmentKind::Prefix));
+            line = &line[prefix.end()..];
+        }
+
+        let mut parts: Vec<String> = line
+            .split([',', ';', '、', '，', '|'])
+            .map(str::to_string)
+            .collect();
+        for conjunction in [" and ", " & "].iter().chain(conjunctions) {
+            parts = parts
+                .iter()
+                .flat_map(|part| split_case_insensitive(part, conjunction))
+                .collect();
+        }
+
+        for part in parts {
+            let part = utils::normalize_whitespace(part.trim());
+            if part.is_empty() {
+                continue;
+            }
+            if utils::looks_like_datetime_segment(&part)
+                || utils::looks_like_live_timestamp_segment(&part)
+            {
+                removed.push(RemovedFragment::new(&part, FragmentKind::Date));
+            } else if looks_like_job_title(&part) {
+                removed.push(RemovedFragment::new(&part, FragmentKind::JobTitle));
+            } else if utils::looks_like_org_credit(&part) && !utils::looks_like_author_name(&part) {
+                removed.push(RemovedFragment::new(&part, FragmentKind::Organization));
+            } else if !names.contains(&part) {
+                names.push(part);
+            }
+        }
+    }
+    names
+}
+
+fn split_case_insensitive(text: &str, separator: &str) -> Vec<String> {
+    let lower = text.to_lowercase();
+    if lower.len() != text.len() {
+        return vec![text.to_string()];
+    }
+
+    let mut parts = Vec::new();
+    let mut start = 0;
+    for (idx, _) in lower.match_indices(separator) {
+        parts.push(text[start..idx].to_string());
+        start = idx + separator.len();
+    }
+    parts.push(text[start..].to_string());
+    parts
+}
+
+/// Whether a byline part is a job title ("Senior Reporter") rather than a name
+fn looks_like_job_title(text: &str) -> bool {
+    const TITLE_WORDS: [&str; 20] = [
+        "reporter",
+        "editor",
+        "writer",
+        "correspondent",
+        "contributor",
+        "columnist",
+        "journalist",
+        "producer",
+        "analyst",
+        "photographer",
+        "anchor",
+        "staff",
+        "senior",
+        "chief",
+        "associate",
+        "contributing",
+        "redakteur",
+        "redakteurin",
+        "journaliste",
+        "periodista",
+    ];
+
+    let lower = text.to_lowercase();
+    let words: Vec<&str> = lower
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect();
+    !words.is_empty() && words.len() <= 5 && words.iter().any(|word| TITLE_WORDS.contains(word))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_byline_removes_dates_handles_and_titles() {
+        let result = clean_byline(
+            "By Jane Smith and Tom Jones\nStaff Writers\n@janesmith\n5 min ago",
+            &BylineContext::default(),
+        );
+        assert_eq!(result.reason, BylineReason::Accepted);
+        assert_eq!(result.names, vec!["Jane Smith", "Tom Jones"]);
+        assert_eq!(
+            result.byline.as_deref(),
+            Some("By Jane Smith and Tom Jones\nStaff Writers")
+        );
+
+        let kinds: Vec<FragmentKind> = result.removed.iter().map(|f| f.kind).collect();
+        assert!(kinds.contains(&FragmentKind::Date));
+        assert!(kinds.contains(&FragmentKind::SocialHandle));
+        assert!(kinds.contains(&FragmentKind::Prefix));
+        assert!(result
+            .removed
+            .iter()
+            .any(|f| f.kind == FragmentKind::JobTitle && f.text == "Staff Writers"));
+    }
+
+    #[test]
+    fn test_clean_byline_without_names() {
+        let context = BylineContext::default();
+
+        let result = clean_byline("5 min ago", &context);
+        assert_eq!(result.reason, BylineReason::Empty);
+        assert_eq!(result.byline, None);
+        assert!(result.names.is_empty());
+        assert!(result.removed.iter().any(|f| f.kind == FragmentKind::Date));
+
+        let result = clean_byline("By AFP", &context);
+        assert_eq!(result.reason, BylineReason::OrgCredit);
+        assert_eq!(result.byline, None);
+        assert!(result.names.is_empty());
+        assert!(result
+            .removed
+            .contains(&RemovedFragment::new("AFP", FragmentKind::Organization)));
+    }
+
+    #[test]
+    fn test_clean_byline_localized() {
+        let context = BylineContext {
+            lang: Some("de-DE".to_string()),
+            ..Default::default()
+        };
+        let result = clean_byline("Von Max Mustermann und Erika Muster | 12.03.2024", &context);
+        assert_eq!(result.names, vec!["Max Mustermann", "Erika Muster"]);
+        assert!(result
+            .removed
+            .contains(&RemovedFragment::new("Von", FragmentKind::Prefix)));
+        assert!(result
+            .removed
+            .contains(&RemovedFragment::new("12.03.2024", FragmentKind::Date)));
+    }
+
+    #[test]
+    fn test_clean_byline_rejections() {
+        let context = BylineContext {
+            site_names: vec!["SIMPLYFOUND.COM | BY: Joe Wee".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            clean_byline("Joe Wee", &context).reason,
+            BylineReason::SiteName
+        );
+        assert_eq!(
+            clean_byline("Our Foreign Staff", &context).reason,
+            BylineReason::OrgCredit
+        );
+        assert_eq!(
+            clean_byline("Follow @example", &context).reason,
+            BylineReason::NotAByline
+        );
+        assert_eq!(clean_byline("  ", &context).reason, BylineReason::Empty);
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::constants::{DIV_TO_P_ELEMS, REGEXPS};
 use crate::error::Result;
+use crate::options::{ElementPolicy, MathNormalization, SemanticElementPolicy, SrcsetSelection};
 use kuchikikiki::{traits::*, NodeData, NodeRef};
 use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
@@ -37,11 +38,131 @@ pub fn clean_article_content(html: &str, base_url: Option<&str>) -> Result<Strin
     Ok(result)
 }
 
+/// Add `dir="rtl"` to right-to-left blocks set in a left-to-right context
+///
+/// Each block without its own `dir` is compared with the nearest `dir` declared
+/// on an ancestor inside the content, or with `article_dir` when there is none.
+/// When the block's first strong character is right-to-left and that direction
+/// isn't, it gets `dir="rtl"`, so e.g. Hebrew quotes in an English article render
+/// correctly. Left-to-right text in a right-to-left context is left to the bidi
+/// algorithm, and the HTML is returned unchanged when no block needs a `dir`.
+pub fn mark_block_directions(html: &str, article_dir: &str) -> String {
+    const BLOCK_SELECTOR: &str =
+        "p, li, blockquote, h1, h2, h3, h4, h5, h6, td, th, dt, dd, figcaption, pre";
+
+    let document = kuchikikiki::parse_html().one(html);
+    let Some(body) = document
+        .select("body")
+        .ok()
+        .and_then(|mut iter| iter.next())
+        .map(|node| node.as_node().clone())
+    else {
+        return html.to_string();
+    };
+    let Ok(blocks) = body.select(BLOCK_SELECTOR) else {
+        return html.to_string();
+    };
+
+    let mut changed = false;
+    for block in blocks.collect::<Vec<_>>() {
+        if block.attributes.borrow().contains("dir") {
+            continue;
+        }
+
+        let context_dir = block
+            .as_node()
+            .ancestors()
+            .take_while(|ancestor| ancestor != &body)
+            .find_map(|ancestor| {
+                let element = ancestor.as_element()?;
+                let dir = element.attributes.borrow().get("dir")?.to_ascii_lowercase();
+                Some(dir)
+            })
+            .unwrap_or_else(|| article_dir.to_ascii_lowercase());
+        if context_dir == "rtl" {
+            continue;
+        }
+
+        let text_dir = crate::dom_utils::detect_text_direction(&block.text_contents());
+        if text_dir == Some("rtl") {
+            block
+                .attributes
+                .borrow_mut()
+                .insert("dir", "rtl".to_string());
+            changed = true;
+        }
+    }
+
+    if changed {
+        serialize_node(&body, true)
+    } else {
+        html.to_string()
+    }
+}
+
+static URL_TAG_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)<(a|img|picture|source|video|audio|figure)\b(?:[^>"']|"[^"]*"|'[^']*')*>"#)
+        .unwrap()
+});
+static URL_ATTRIBUTE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)(\s)(href|src|poster|srcset)(\s*=\s*)(?:"([^"]*)"|'([^']*)')"#).unwrap()
+});
+
 /// Fix relative URLs in HTML string using regex
-fn fix_relative_urls_in_html(html: &str, _base_url: &str) -> String {
-    // For now, just return as-is
-    // TODO: Implement proper URL fixing without re-parsing the entire tree
-    html.to_string()
+///
+/// Like Mozilla's _fixRelativeUris, resolves `href` on links and `src`, `poster` and
+/// every `srcset` candidate on media elements. Works on the serialized HTML so the
+/// tree is not re-parsed. In-page `#fragment` links and URLs that are already
+/// absolute are left as written.
+fn fix_relative_urls_in_html(html: &str, base_url: &str) -> String {
+    if url::Url::parse(base_url).is_err() {
+        return html.to_string();
+    }
+
+    URL_TAG_REGEX
+        .replace_all(html, |tag: &Captures| {
+            let is_link = tag[1].eq_ignore_ascii_case("a");
+            URL_ATTRIBUTE_REGEX
+                .replace_all(&tag[0], |attr: &Captures| {
+                    let name = attr[2].to_ascii_lowercase();
+                    if is_link != (name == "href") {
+                        return attr[0].to_string();
+                    }
+                    let value = attr
+                        .get(4)
+                        .or_else(|| attr.get(5))
+                        .map_or("", |m| m.as_str());
+                    match resolve_attribute_url(&name, value, base_url) {
+                        Some(resolved) => {
+                            format!("{}{}{}\"{resolved}\"", &attr[1], &attr[2], &attr[3])
+                        }
+                        None => attr[0].to_string(),
+                    }
+                })
+                .into_owned()
+        })
+        .into_owned()
+}
+
+/// Resolve one URL attribute value, returning the escaped value to write back
+fn resolve_attribute_url(name: &str, value: &str, base_url: &str) -> Option<String> {
+    let value = crate::utils::unescape_html_entities(value);
+    let trimmed = value.trim();
+    if trimmed.is_empty() || trimmed.starts_with('#') {
+        return None;
+    }
+
+    let resolved = if name == "srcset" {
+        crate::images::resolve_srcset(trimmed, base_url)
+    } else if url::Url::parse(trimmed).is_ok() {
+        return None;
+    } else {
+        crate::utils::resolve_url(trimmed, base_url)?
+    };
+    if resolved == trimmed {
+        return None;
+    }
+    Some(resolved.replace('&', "&amp;").replace('"', "&quot;"))
 }
 
 /// Remove nav-like sections using lightweight regex patterns.
@@ -824,17 +945,1042 @@ fn replace_brs_in_content(content: &str) -> String {
     }
 }
 
+static PULLQUOTE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)pull-?quote|pull_quote|pullout").unwrap());
+
+/// Whether a class or id string marks an element as a pull quote
+pub fn is_pullquote(class_and_id: &str) -> bool {
+    PULLQUOTE_REGEX.is_match(class_and_id)
+}
+
+/// Keep, unwrap or drop semantic HTML5 elements in extracted content
+///
+/// Pull quotes are matched before other `<aside>` elements, so a pull quote
+/// follows [`SemanticElementPolicy::pullquote`] even when asides are dropped.
+/// Returns the HTML unchanged when no element with another policy than
+/// [`ElementPolicy::Keep`] is present.
+pub fn apply_semantic_policy(html: &str, policy: &SemanticElementPolicy) -> String {
+    let rules: [(&str, ElementPolicy); 7] = [
+        ("figcaption", policy.figcaption),
+        ("figure", policy.figure),
+        ("summary", policy.summary),
+        ("details", policy.details),
+        ("aside", policy.aside),
+        ("dl", policy.dl),
+        ("time", policy.time),
+    ];
+    // Skip parsing when no element with a non-keep policy is present; re-serializing
+    // would otherwise reshape the content
+    let lower = html.to_ascii_lowercase();
+    let has_tag = |tag: &str| lower.contains(&format!("<{tag}"));
+    let needs_pass = rules
+        .iter()
+        .any(|(tag, rule)| *rule != ElementPolicy::Keep && has_tag(tag))
+        || (policy.pullquote != ElementPolicy::Keep && (has_tag("aside") || has_tag("blockquote")));
+    if !needs_pass {
+        return html.to_string();
+    }
+
+    let document = kuchikikiki::parse_html().one(html);
+    let Ok(body) = document.select_first("body") else {
+        return html.to_string();
+    };
+    let body = body.as_node().clone();
+    let mut changed = false;
+
+    let pullquotes: Vec<NodeRef> = body
+        .select("aside, blockquote")
+        .map(|matches| {
+            matches
+                .filter(|element| {
+                    let attributes = element.attributes.borrow();
+                    let class = attributes.get("class").unwrap_or_default();
+                    let id = attributes.get("id").unwrap_or_default();
+                    is_pullquote(&format!("{class} {id}"))
+                })
+                .map(|element| element.as_node().clone())
+                .collect()
+        })
+        .unwrap_or_default();
+    for node in &pullquotes {
+        changed |= apply_element_policy(node, policy.pullquote);
+    }
+
+    for (tag, rule) in rules {
+        let Ok(matches) = body.select(tag) else {
+            continue;
+        };
+        let nodes: Vec<NodeRef> = matches.map(|m| m.as_node().clone()).collect();
+        for node in nodes {
+            if pullquotes.contains(&node) {
+                continue;
+            }
+            changed |= apply_element_policy(&node, rule);
+        }
+    }
+
+    if changed {
+        serialize_node(&body, true)
+    } else {
+        html.to_string()
+    }
+}
+
+fn apply_element_policy(node: &NodeRef, policy: ElementPolicy) -> bool {
+    match policy {
+        ElementPolicy::Keep => return false,
+        ElementPolicy::Unwrap => {
+            for child in node.children().collect::<Vec<_>>() {
+                node.insert_before(child);
+            }
+        }
+        ElementPolicy::Drop => {}
+    }
+    node.detach();
+    true
+}
+
+static BASE64_IMAGE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)^data:\s*([^\s;,]+)\s*;\s*base64\s*,").unwrap());
+static IMAGE_URL_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)^\s*\S+\.(jpg|jpeg|png|webp|gif|avif)\S*\s*$").unwrap());
+static IMAGE_SRCSET_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\.(jpg|jpeg|png|webp|gif|avif)\S*\s+\d").unwrap());
+static LAZY_CLASS_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)lazy").unwrap());
+
+/// Attributes lazy-loading libraries use for the real image, by the attribute they stand in for
+const LAZY_IMAGE_ATTRIBUTES: [(&str, &str); 6] = [
+    ("data-srcset", "srcset"),
+    ("data-lazy-srcset", "srcset"),
+    ("data-src", "src"),
+    ("data-original", "src"),
+    ("data-lazy-src", "src"),
+    ("data-lazyload", "src"),
+];
+
+/// Normalize lazy-loaded images so they point at the real image
+///
+/// Mirrors Mozilla's _fixLazyImages:
+/// - Drops small base64 placeholder `src` values when another attribute holds an image URL
+/// - Copies `data-src`, `data-srcset`, `data-original` and `data-lazy-src` (or any other
+///   attribute holding an image URL) into `src`/`srcset` when those are missing, or when
+///   the element is still marked as lazy
+/// - Gives `<picture>` elements without a usable `<img>` one built from their first `<source>`
+/// - Adds an `<img>` to `<figure>` elements that only carry the image URL in an attribute
+///
+/// Returns the HTML unchanged when no image needed fixing.
+pub fn fix_lazy_images(html: &str) -> String {
+    let lower = html.to_ascii_lowercase();
+    if !["<img", "<picture", "<figure"]
+        .iter()
+        .any(|tag| lower.contains(tag))
+    {
+        return html.to_string();
+    }
+
+    let document = kuchikikiki::parse_html().one(html);
+    let is_document = ["<html", "<head", "<body"]
+        .iter()
+        .any(|tag| lower.contains(tag));
+    let root = if is_document {
+        document.clone()
+    } else {
+        match document.select_first("body") {
+            Ok(body) => body.as_node().clone(),
+            Err(_) => return html.to_string(),
+        }
+    };
+
+    let mut changed = false;
+    if let Ok(sources) = root.select("picture > source") {
+        for source in sources.collect::<Vec<_>>() {
+            changed |= copy_lazy_attributes(source.as_node(), true);
+        }
+    }
+    if let Ok(elements) = root.select("img, picture, figure") {
+        for element in elements.collect::<Vec<_>>() {
+            changed |= fix_lazy_image(element.as_node());
+        }
+    }
+    if let Ok(pictures) = root.select("picture") {
+        for picture in pictures.collect::<Vec<_>>() {
+            changed |= fix_picture_image(picture.as_node());
+        }
+    }
+
+    if changed {
+        serialize_node(&root, !is_document)
+    } else {
+        html.to_string()
+    }
+}
+
+fn fix_lazy_image(node: &NodeRef) -> bool {
+    let Some(element) = node.as_element() else {
+        return false;
+    };
+    let mut changed = false;
+
+    // A tiny base64 image next to a real image URL is a placeholder
+    {
+        let mut attributes = element.attributes.borrow_mut();
+        let placeholder = attributes.get("src").and_then(|src| {
+            let caps = BASE64_IMAGE_REGEX.captures(src)?;
+            let is_svg = caps[1].eq_ignore_ascii_case("image/svg+xml");
+            let payload = src.len() - caps[0].len();
+            (!is_svg && payload < 133).then_some(())
+        });
+        let has_real_image = attributes.map.iter().any(|(name, attr)| {
+            &*name.local != "src"
+                && (IMAGE_URL_REGEX.is_match(&attr.value)
+                    || IMAGE_SRCSET_REGEX.is_match(&attr.value)
+                    || LAZY_IMAGE_ATTRIBUTES
+                        .iter()
+                        .any(|(lazy, _)| *lazy == &*name.local && !attr.value.trim().is_empty()))
+        });
+        if placeholder.is_some() && has_real_image {
+            attributes.remove("src");
+            changed = true;
+        }
+    }
+
+    changed | copy_lazy_attributes(node, false)
+}
+
+/// Copy the lazy-loaded image URL of `node` into `src`/`srcset`
+///
+/// `<source>` elements only take a `srcset`.
+fn copy_lazy_attributes(node: &NodeRef, srcset_only: bool) -> bool {
+    let Some(element) = node.as_element() else {
+        return false;
+    };
+    let tag = element.name.local.to_string();
+
+    let copies = {
+        let attributes = element.attributes.borrow();
+        let has_value = |name: &str| {
+            attributes
+                .get(name)
+                .is_some_and(|value| !value.trim().is_empty())
+        };
+        let has_src = has_value("src") && !srcset_only;
+        let has_srcset = has_value("srcset") && attributes.get("srcset") != Some("null");
+        let is_lazy = LAZY_CLASS_REGEX.is_match(attributes.get("class").unwrap_or_default());
+        if (has_src || has_srcset) && !is_lazy {
+            return false;
+        }
+
+        let mut copies: Vec<(&str, String)> = Vec::new();
+        for (lazy, target) in LAZY_IMAGE_ATTRIBUTES {
+            if let Some(value) = attributes.get(lazy).filter(|v| !v.trim().is_empty()) {
+                if !copies.iter().any(|(existing, _)| *existing == target) {
+                    copies.push((target, value.trim().to_string()));
+                }
+            }
+        }
+        // Fall back to any other attribute that holds an image URL
+        for (name, attr) in attributes.map.iter() {
+            if matches!(&*name.local, "src" | "srcset" | "alt") {
+                continue;
+            }
+            let target = if IMAGE_SRCSET_REGEX.is_match(&attr.value) {
+                "srcset"
+            } else if IMAGE_URL_REGEX.is_match(&attr.value) {
+                "src"
+            } else {
+                continue;
+            };
+            if !copies.iter().any(|(existing, _)| *existing == target) {
+                copies.push((target, attr.value.trim().to_string()));
+            }
+        }
+        copies
+    };
+
+    let copies: Vec<_> = copies
+        .into_iter()
+        .filter(|(target, _)| !srcset_only || *target == "srcset")
+        .collect();
+    if copies.is_empty() {
+        return false;
+    }
+
+    match tag.as_str() {
+        "img" | "picture" | "source" => {
+            let mut attributes = element.attributes.borrow_mut();
+            let mut changed = false;
+            for (target, value) in copies {
+                if attributes.get(target) != Some(value.as_str()) {
+                    attributes.insert(target, value);
+                    changed = true;
+                }
+            }
+            changed
+        }
+        "figure" => {
+            if node.select_first("img, picture").is_ok() {
+                return false;
+            }
+            let img = new_element("img");
+            if let Some(img_element) = img.as_element() {
+                let mut attributes = img_element.attributes.borrow_mut();
+                for (target, value) in copies {
+                    attributes.insert(target, value);
+                }
+            }
+            node.append(img);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Give a `<picture>` without a usable `<img>` one built from its first `<source>`
+fn fix_picture_image(picture: &NodeRef) -> bool {
+    let srcset = picture.select("source").ok().and_then(|mut sources| {
+        sources.find_map(|source| {
+            let attributes = source.attributes.borrow();
+            attributes
+                .get("srcset")
+                .map(str::trim)
+                .filter(|srcset| !srcset.is_empty() && *srcset != "null")
+                .map(str::to_string)
+        })
+    });
+    let Some(srcset) = srcset else {
+        return false;
+    };
+    let Some(src) = crate::images::parse_srcset(&srcset)
+        .into_iter()
+        .next()
+        .map(|candidate| candidate.url)
+    else {
+        return false;
+    };
+
+    match picture.select_first("img") {
+        Ok(img) => {
+            let mut attributes = img.attributes.borrow_mut();
+            let usable = attributes.get("src").is_some_and(|src| {
+                !src.trim().is_empty() && !src.trim_start().starts_with("data:")
+            });
+            if usable {
+                return false;
+            }
+            attributes.remove("src");
+            attributes.insert("src", src);
+            if !attributes.contains("srcset") {
+                attributes.insert("srcset", srcset);
+            }
+            true
+        }
+        Err(_) => {
+            let img = new_element("img");
+            if let Some(element) = img.as_element() {
+                let mut attributes = element.attributes.borrow_mut();
+                attributes.insert("src", src);
+                attributes.insert("srcset", srcset);
+            }
+            picture.append(img);
+            true
+        }
+    }
+}
+
+/// Collapse `srcset` and `sizes` on images in extracted content to a single `src`
+///
+/// Each `<picture>` is replaced by its `<img>`, pointed at the candidate of the first
+/// `<source>` a browser would use (see [`SrcsetSelection`]), falling back to the
+/// `<img>` itself. Returns the HTML unchanged for [`SrcsetSelection::Keep`] or when
+/// no image has a `srcset`.
+pub fn collapse_srcset(html: &str, selection: SrcsetSelection) -> String {
+    if selection == SrcsetSelection::Keep || !html.to_ascii_lowercase().contains("srcset") {
+        return html.to_string();
+    }
+
+    let document = kuchikikiki::parse_html().one(html);
+    let Ok(body) = document.select_first("body") else {
+        return html.to_string();
+    };
+    let body = body.as_node().clone();
+    let mut changed = false;
+
+    if let Ok(pictures) = body.select("picture") {
+        for picture in pictures.collect::<Vec<_>>() {
+            collapse_picture(picture.as_node(), selection);
+            changed = true;
+        }
+    }
+    if let Ok(images) = body.select("img[srcset], img[sizes]") {
+        for img in images.collect::<Vec<_>>() {
+            let mut attributes = img.attributes.borrow_mut();
+            let srcset = attributes.get("srcset").unwrap_or_default().to_string();
+            let sizes = attributes.get("sizes").map(str::to_string);
+            let src = attributes.get("src").unwrap_or_default().trim().to_string();
+            if let Some(url) = pick_srcset_url(&srcset, sizes.as_deref(), &src, selection) {
+                attributes.insert("src", url);
+            }
+            attributes.remove("srcset");
+            attributes.remove("sizes");
+            changed = true;
+        }
+    }
+
+    if changed {
+        serialize_node(&body, true)
+    } else {
+        html.to_string()
+    }
+}
+
+/// Replace a `<picture>` with its `<img>`, pointed at the best candidate
+fn collapse_picture(picture: &NodeRef, selection: SrcsetSelection) {
+    const IMAGE_TYPES: [&str; 5] = [
+        "image/jpeg",
+        "image/png",
+        "image/gif",
+        "image/webp",
+        "image/svg+xml",
+    ];
+
+    let source = picture.select("source").ok().and_then(|mut sources| {
+        sources.find_map(|source| {
+            let attributes = source.attributes.borrow();
+            let media_matches = attributes.get("media").is_none_or(|media| match selection {
+                SrcsetSelection::Width(viewport) => crate::images::media_matches(media, viewport),
+                _ => false,
+            });
+            let type_supported = attributes.get("type").is_none_or(|kind| {
+                IMAGE_TYPES.contains(&kind.trim().to_ascii_lowercase().as_str())
+            });
+            let srcset = attributes.get("srcset")?;
+            (media_matches && type_supported).then(|| {
+                (
+                    srcset.to_string(),
+                    attributes.get("sizes").map(str::to_string),
+                )
+            })
+        })
+    });
+
+    let img = match picture.select_first("img") {
+        Ok(img) => img.as_node().clone(),
+        Err(_) => new_element("img"),
+    };
+    if let Some(element) = img.as_element() {
+        let mut attributes = element.attributes.borrow_mut();
+        let src = attributes.get("src").unwrap_or_default().trim().to_string();
+        let (srcset, sizes) = match source {
+            Some(source) => source,
+            None => (
+                attributes.get("srcset").unwrap_or_default().to_string(),
+                attributes.get("sizes").map(str::to_string),
+            ),
+        };
+        if let Some(url) = pick_srcset_url(&srcset, sizes.as_deref(), &src, selection) {
+            attributes.insert("src", url);
+        }
+        attributes.remove("srcset");
+        attributes.remove("sizes");
+    }
+
+    if img
+        .as_element()
+        .is_some_and(|element| element.attributes.borrow().contains("src"))
+    {
+        picture.insert_before(img);
+    }
+    picture.detach();
+}
+
+/// Pick the URL to use from a `srcset`, counting `src` as the 1x candidate like browsers do
+fn pick_srcset_url(
+    srcset: &str,
+    sizes: Option<&str>,
+    src: &str,
+    selection: SrcsetSelection,
+) -> Option<String> {
+    let mut candidates = crate::images::parse_srcset(srcset);
+    let has_width = candidates
+        .iter()
+        .any(|candidate| candidate.descriptor.ends_with(['w', 'W']));
+    let has_1x = candidates
+        .iter()
+        .any(|candidate| matches!(candidate.descriptor.as_str(), "" | "1x" | "1.0x"));
+    if !src.is_empty() && !src.starts_with("data:") && !has_width && !has_1x {
+        candidates.push(crate::images::SrcsetCandidate {
+            url: src.to_string(),
+            descriptor: String::new(),
+        });
+    }
+    crate::images::select_srcset_candidate(&candidates, sizes, selection)
+        .map(|candidate| candidate.url.clone())
+}
+
+/// Create a detached, attribute-less element
+fn new_element(tag: &str) -> NodeRef {
+    let document = kuchikikiki::parse_html().one(format!("<{tag}></{tag}>"));
+    let element = document
+        .select_first(tag)
+        .map(|element| element.as_node().clone())
+        .unwrap_or(document);
+    element.detach();
+    element
+}
+
+static LANGUAGE_CLASS_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)^(?:language|lang)-(.+)$").unwrap());
+static GITHUB_LANGUAGE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)^highlight-(?:source|text)-([^-]+)").unwrap());
+static SPHINX_LANGUAGE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)^highlight-(.+)$").unwrap());
+
+/// Normalize code blocks so their language survives as `class="language-xxx"`
+///
+/// The language is read from common highlighter markup on the `<pre>`, its `<code>`
+/// or up to three wrapping elements:
+/// - Prism and highlight.js `language-xxx`/`lang-xxx` classes, and `data-lang`
+/// - highlight.js `hljs xxx` classes
+/// - GitHub `highlight-source-xxx`/`highlight-text-xxx` wrappers
+/// - Sphinx (Pygments) `highlight-xxx` wrappers
+///
+/// The class goes on the `<code>` element of the block, or on the `<pre>` when it has
+/// none. Pygments line-number tables are replaced by their code cell so line numbers
+/// don't end up in the code. Returns the HTML unchanged when no block needed fixing.
+pub fn normalize_code_blocks(html: &str) -> String {
+    if !html.to_ascii_lowercase().contains("<pre") {
+        return html.to_string();
+    }
+
+    let document = kuchikikiki::parse_html().one(html);
+    let mut changed = false;
+
+    if let Ok(tables) = document.select("table") {
+        for table in tables.collect::<Vec<_>>() {
+            let table = table.as_node();
+            let line_numbers = table.select_first("td.linenos");
+            let (Ok(_), Ok(code_cell)) = (line_numbers, table.select_first("td.code")) else {
+                continue;
+            };
+            if code_cell.as_node().select_first("pre").is_err() {
+                continue;
+            }
+            for child in code_cell.as_node().children().collect::<Vec<_>>() {
+                table.insert_before(child);
+            }
+            table.detach();
+            changed = true;
+        }
+    }
+
+    if let Ok(blocks) = document.select("pre") {
+        for pre in blocks.collect::<Vec<_>>() {
+            changed |= set_code_language(pre.as_node());
+        }
+    }
+
+    if changed {
+        serialize_node(&document, false)
+    } else {
+        html.to_string()
+    }
+}
+
+fn set_code_language(pre: &NodeRef) -> bool {
+    // A lone <code> child carries the class, as in <pre><code class="language-xxx">
+    let mut elements = pre.children().filter(|child| child.as_element().is_some());
+    let code = match (elements.next(), elements.next()) {
+        (Some(child), None) if child.as_element().is_some_and(|e| &*e.name.local == "code") => {
+            Some(child)
+        }
+        _ => None,
+    };
+    let target = code.clone().unwrap_or_else(|| pre.clone());
+
+    let block_language = code
+        .iter()
+        .chain(std::iter::once(pre))
+        .find_map(|node| element_language(node, true));
+    let Some(language) = block_language.or_else(|| {
+        pre.ancestors()
+            .take(3)
+            .take_while(|ancestor| {
+                ancestor
+                    .as_element()
+                    .is_some_and(|e| &*e.name.local != "body")
+            })
+            .find_map(|ancestor| element_language(&ancestor, false))
+    }) else {
+        return false;
+    };
+
+    let Some(element) = target.as_element() else {
+        return false;
+    };
+    let mut attributes = element.attributes.borrow_mut();
+    let class = attributes.get("class").unwrap_or_default();
+    let normalized = format!("language-{language}");
+    if class.split_whitespace().any(|token| token == normalized) {
+        return false;
+    }
+    let mut tokens: Vec<&str> = class
+        .split_whitespace()
+        .filter(|token| !LANGUAGE_CLASS_REGEX.is_match(token))
+        .collect();
+    tokens.push(&normalized);
+    let class = tokens.join(" ");
+    attributes.insert("class", class);
+    true
+}
+
+/// Language named by an element's highlighter classes or `data-lang`
+///
+/// `block` enables the `lang-xxx` and bare highlight.js `hljs xxx` forms, which are
+/// only trusted on the `<pre>` and `<code>` elements themselves; on wrappers they are
+/// too easily confused with locale or layout classes.
+fn element_language(node: &NodeRef, block: bool) -> Option<String> {
+    let element = node.as_element()?;
+    let attributes = element.attributes.borrow();
+    let class = attributes.get("class").unwrap_or_default();
+
+    let from_class = class.split_whitespace().find_map(|token| {
+        if !block && token.to_ascii_lowercase().starts_with("lang-") {
+            return None;
+        }
+        let language = LANGUAGE_CLASS_REGEX
+            .captures(token)
+            .or_else(|| GITHUB_LANGUAGE_REGEX.captures(token))
+            .or_else(|| SPHINX_LANGUAGE_REGEX.captures(token))?;
+        normalize_code_language(&language[1])
+    });
+    let from_hljs = || {
+        let mut tokens = class.split_whitespace();
+        if !block || !tokens.clone().any(|token| token == "hljs") {
+            return None;
+        }
+        tokens
+            .find(|token| *token != "hljs" && !token.starts_with("hljs-"))
+            .and_then(normalize_code_language)
+    };
+
+    from_class.or_else(from_hljs).or_else(|| {
+        attributes
+            .get("data-lang")
+            .and_then(normalize_code_language)
+    })
+}
+
+/// Lowercase a highlighter language name and map common aliases to one spelling
+fn normalize_code_language(language: &str) -> Option<String> {
+    let language = language.trim().to_ascii_lowercase();
+    let valid = !language.is_empty()
+        && language
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '#' | '-' | '_' | '.'));
+    let ignored = matches!(
+        language.as_str(),
+        "none" | "nohighlight" | "no-highlight" | "default"
+    );
+    if !valid || ignored {
+        return None;
+    }
+
+    let language = match language.as_str() {
+        "js" => "javascript",
+        "ts" => "typescript",
+        "py" | "python3" => "python",
+        "rb" => "ruby",
+        "rs" => "rust",
+        "golang" => "go",
+        "sh" | "shell" | "zsh" => "bash",
+        "yml" => "yaml",
+        "md" => "markdown",
+        "c++" | "cxx" | "cc" => "cpp",
+        "c#" | "cs" => "csharp",
+        other => other,
+    };
+    Some(language.to_string())
+}
+
+/// Rendered math from MathJax 2 and 3, KaTeX and MediaWiki
+const MATH_CONTAINER_SELECTOR: &str = "mjx-container, .MathJax, .MathJax_Display, .MathJax_SVG, \
+    .MathJax_SVG_Display, .MathJax_CHTML, .katex-display, .katex, .mwe-math-element";
+
+/// Source forms found for one formula
+struct MathSource {
+    mathml: Option<NodeRef>,
+    tex: Option<String>,
+    display: bool,
+}
+
+/// Replace rendered math with its source form
+///
+/// Handles MathJax 2 (`<script type="math/tex">` and `math/mml` next to the rendered
+/// frame, or the frame's `data-mathml`), MathJax 3 `<mjx-container>`, KaTeX and
+/// MediaWiki `mwe-math-element`, whose MathML and TeX live in hidden duplicates.
+/// With [`MathNormalization::Tex`], plain `<math>` elements carrying a TeX annotation
+/// are converted as well. MathJax previews are always dropped. Returns the HTML
+/// unchanged for [`MathNormalization::Off`] or when nothing was replaced.
+pub fn normalize_math(html: &str, mode: MathNormalization) -> String {
+    const MARKERS: [&str; 6] = [
+        "<math",
+        "math/",
+        "mathjax",
+        "mjx-container",
+        "katex",
+        "mwe-math",
+    ];
+    if mode == MathNormalization::Off {
+        return html.to_string();
+    }
+    let lower = html.to_ascii_lowercase();
+    if !MARKERS.iter().any(|marker| lower.contains(marker)) {
+        return html.to_string();
+    }
+
+    let document = kuchikikiki::parse_html().one(html);
+    let mut changed = false;
+
+    if let Ok(scripts) = document.select(r#"script[type^="math/"]"#) {
+        for script in scripts.collect::<Vec<_>>() {
+            let (kind, id) = {
+                let attributes = script.attributes.borrow();
+                let kind = attributes
+                    .get("type")
+                    .unwrap_or_default()
+                    .to_ascii_lowercase();
+                (kind, attributes.get("id").map(str::to_string))
+            };
+            let source = script.as_node().text_contents();
+            let math = if kind.starts_with("math/tex") {
+                MathSource {
+                    mathml: None,
+                    tex: clean_tex(&source),
+                    display: kind.contains("mode=display"),
+                }
+            } else if kind.starts_with("math/mml") {
+                let mathml = parse_mathml(&source);
+                let display = mathml.as_ref().is_some_and(is_display_mathml);
+                MathSource {
+                    mathml,
+                    tex: None,
+                    display,
+                }
+            } else {
+                continue;
+            };
+            let Some(replacement) = math_replacement(math, mode) else {
+                continue;
+            };
+
+            // Drop the frame MathJax rendered for this script, with its display wrapper
+            let frame = id
+                .filter(|id| !id.contains('"'))
+                .and_then(|id| document.select_first(&format!(r#"[id="{id}-Frame"]"#)).ok());
+            if let Some(frame) = frame {
+                let frame = frame.as_node();
+                let wrapper = frame.parent().filter(|parent| {
+                    parent.as_element().is_some_and(|element| {
+                        let attributes = element.attributes.borrow();
+                        let class = attributes.get("class").unwrap_or_default();
+                        class
+                            .split_whitespace()
+                            .any(|c| c.starts_with("MathJax") && c.ends_with("Display"))
+                    })
+                });
+                wrapper.as_ref().unwrap_or(frame).detach();
+            }
+            script.as_node().insert_before(replacement);
+            script.as_node().detach();
+            changed = true;
+        }
+    }
+
+    if let Ok(previews) = document.select(".MathJax_Preview") {
+        for preview in previews.collect::<Vec<_>>() {
+            preview.as_node().detach();
+            changed = true;
+        }
+    }
+
+    if let Ok(containers) = document.select(MATH_CONTAINER_SELECTOR) {
+        for container in containers.collect::<Vec<_>>() {
+            let container = container.as_node();
+            // Containers nested in an already replaced one went with it
+            if !container
+                .ancestors()
+                .any(|ancestor| ancestor.as_document().is_some())
+            {
+                continue;
+            }
+            if let Some(replacement) = math_replacement(container_math(container), mode) {
+                container.insert_before(replacement);
+                container.detach();
+                changed = true;
+            }
+        }
+    }
+
+    if mode == MathNormalization::Tex {
+        if let Ok(elements) = document.select("math") {
+            for math in elements.collect::<Vec<_>>() {
+                let math = math.as_node();
+                let Some(tex) = mathml_tex(math) else {
+                    continue;
+                };
+                let source = MathSource {
+                    mathml: None,
+                    display: is_display_mathml(math),
+                    tex: Some(tex),
+                };
+                if let Some(replacement) = math_replacement(source, mode) {
+                    math.insert_before(replacement);
+                    math.detach();
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    if changed {
+        serialize_node(&document, false)
+    } else {
+        html.to_string()
+    }
+}
+
+/// Collect the MathML and TeX hidden in a rendered math container
+fn container_math(container: &NodeRef) -> MathSource {
+    let mathml = container
+        .select_first("math")
+        .ok()
+        .map(|math| math.as_node().clone())
+        .or_else(|| {
+            let element = container.as_element()?;
+            let attributes = element.attributes.borrow();
+            parse_mathml(attributes.get("data-mathml")?)
+        });
+    let tex = mathml.as_ref().and_then(mathml_tex).or_else(|| {
+        let img = container
+            .select_first("img[class^=\"mwe-math-fallback\"]")
+            .ok()?;
+        let attributes = img.attributes.borrow();
+        attributes.get("alt").and_then(clean_tex)
+    });
+
+    let display = container.inclusive_descendants().elements().any(|element| {
+        let attributes = element.attributes.borrow();
+        let class = attributes.get("class").unwrap_or_default();
+        class.split_whitespace().any(|c| {
+            matches!(
+                c,
+                "katex-display" | "MathJax_Display" | "MathJax_SVG_Display"
+            ) || c.starts_with("mwe-math-") && c.ends_with("-display")
+        }) || (&*element.name.local == "mjx-container" && attributes.get("display") == Some("true"))
+    }) || mathml.as_ref().is_some_and(is_display_mathml);
+
+    MathSource {
+        mathml,
+        tex,
+        display,
+    }
+}
+
+/// Build the element that replaces a formula, or `None` when no source was found
+fn math_replacement(math: MathSource, mode: MathNormalization) -> Option<NodeRef> {
+    let use_mathml = mode == MathNormalization::MathMl || math.tex.is_none();
+    if let Some(mathml) = math.mathml.filter(|_| use_mathml) {
+        mathml.detach();
+        if let Some(element) = mathml.as_element() {
+            let mut attributes = element.attributes.borrow_mut();
+            if let Some(tex) = math.tex.filter(|_| !attributes.contains("alttext")) {
+                attributes.insert("alttext", tex);
+            }
+            if math.display && !attributes.contains("display") {
+                attributes.insert("display", "block".to_string());
+            }
+        }
+        return Some(mathml);
+    }
+
+    let tex = math.tex?;
+    let span = new_element("span");
+    let (class, text) = if math.display {
+        ("math display", format!("\\[{tex}\\]"))
+    } else {
+        ("math inline", format!("\\({tex}\\)"))
+    };
+    if let Some(element) = span.as_element() {
+        element
+            .attributes
+            .borrow_mut()
+            .insert("class", class.to_string());
+    }
+    span.append(NodeRef::new_text(text));
+    Some(span)
+}
+
+/// TeX source of a MathML element, from its TeX annotation or `alttext`
+fn mathml_tex(math: &NodeRef) -> Option<String> {
+    let annotation = math
+        .select_first(r#"annotation[encoding="application/x-tex"]"#)
+        .ok()
+        .and_then(|annotation| clean_tex(&annotation.text_contents()));
+    annotation.or_else(|| {
+        let element = math.as_element()?;
+        let attributes = element.attributes.borrow();
+        attributes.get("alttext").and_then(clean_tex)
+    })
+}
+
+fn is_display_mathml(math: &NodeRef) -> bool {
+    math.as_element()
+        .is_some_and(|element| element.attributes.borrow().get("display") == Some("block"))
+}
+
+/// Parse serialized MathML into a detached `<math>` element
+fn parse_mathml(source: &str) -> Option<NodeRef> {
+    let document = kuchikikiki::parse_html().one(source);
+    let math = document.select_first("math").ok()?.as_node().clone();
+    math.detach();
+    Some(math)
+}
+
+/// Trim TeX source and drop the `{\displaystyle ...}` wrapper MediaWiki adds
+pub(crate) fn clean_tex(tex: &str) -> Option<String> {
+    let tex = tex.trim();
+    let unwrapped = ["{\\displaystyle", "{\\textstyle"]
+        .iter()
+        .find_map(|prefix| tex.strip_prefix(prefix)?.strip_suffix('}'))
+        .map_or(tex, str::trim);
+    (!unwrapped.is_empty()).then(|| unwrapped.to_string())
+}
+
+/// Swap placeholder images for the `<noscript>` image that follows them
+///
+/// Mirrors Mozilla's _unwrapNoscriptImages: when a `<noscript>` holds a single image
+/// and its previous element sibling is a single image too, the noscript image replaces
+/// that sibling and the `<noscript>` is dropped. Sources of the old image that the new
+/// one lacks are copied over, and differing ones are kept as `data-old-*` attributes.
+///
+/// Returns the HTML unchanged when no image was swapped.
+pub fn unwrap_noscript_images(html: &str) -> String {
+    if !html.to_ascii_lowercase().contains("<noscript") {
+        return html.to_string();
+    }
+
+    let document = kuchikikiki::parse_html().one(html);
+    let Ok(noscripts) = document.select("noscript") else {
+        return html.to_string();
+    };
+
+    let mut changed = false;
+    for noscript in noscripts.collect::<Vec<_>>() {
+        let noscript = noscript.as_node();
+        // The parser keeps <noscript> content as text, so parse it on its own
+        let content = if noscript.children().all(|child| child.as_text().is_some()) {
+            noscript.text_contents()
+        } else {
+            serialize_node(noscript, true)
+        };
+        let fragment = kuchikikiki::parse_html().one(content);
+        let Ok(body) = fragment.select_first("body") else {
+            continue;
+        };
+        if !is_single_image(body.as_node()) {
+            continue;
+        }
+        let Some(previous) = noscript
+            .preceding_siblings()
+            .find(|n| n.as_element().is_some())
+        else {
+            continue;
+        };
+        if !is_single_image(&previous) {
+            continue;
+        }
+        let (Some(replacement), Ok(new_image)) = (
+            body.as_node().children().find(|n| n.as_element().is_some()),
+            body.as_node().select_first("img"),
+        ) else {
+            continue;
+        };
+
+        let old_image = if previous
+            .as_element()
+            .is_some_and(|el| &*el.name.local == "img")
+        {
+            Some(previous.clone())
+        } else {
+            previous
+                .select_first("img")
+                .ok()
+                .map(|img| img.as_node().clone())
+        };
+        if let Some(old) = old_image.as_ref().and_then(|node| node.as_element()) {
+            let old_attributes = old.attributes.borrow();
+            let mut new_attributes = new_image.attributes.borrow_mut();
+            for (name, attr) in old_attributes.map.iter() {
+                let name = &*name.local;
+                let value = attr.value.as_str();
+                let is_source = matches!(name, "src" | "srcset") || IMAGE_URL_REGEX.is_match(value);
+                if value.is_empty() || !is_source || new_attributes.get(name) == Some(value) {
+                    continue;
+                }
+                if new_attributes.contains(name) {
+                    new_attributes.insert(format!("data-old-{name}"), value.to_string());
+                } else {
+                    new_attributes.insert(name, value.to_string());
+                }
+            }
+        }
+
+        replacement.detach();
+        previous.insert_before(replacement);
+        previous.detach();
+        noscript.detach();
+        changed = true;
+    }
+
+    if changed {
+        serialize_node(&document, false)
+    } else {
+        html.to_string()
+    }
+}
+
+/// An `<img>`, or an element whose only content is a single image
+fn is_single_image(node: &NodeRef) -> bool {
+    if node
+        .as_element()
+        .is_some_and(|element| &*element.name.local == "img")
+    {
+        return true;
+    }
+    let mut children = node.children().filter(|child| child.as_element().is_some());
+    match (children.next(), children.next()) {
+        (Some(child), None) => node.text_contents().trim().is_empty() && is_single_image(&child),
+        _ => false,
+    }
+}
+
 /// Prepare document for readability processing
 ///
 /// This function implements Mozilla's _prepDocument functionality:
 /// - Remove script and style elements
 /// - Replace font tags with span
-/// - Unwrap noscript tags to reveal lazy-loaded images
+/// - Unwrap noscript tags to reveal lazy-loaded images, replacing the placeholder image
+///   in front of them (see [`unwrap_noscript_images`])
+/// - Point lazy-loaded images at their real source (see [`fix_lazy_images`])
+/// - Mark the language of code blocks (see [`normalize_code_blocks`])
+/// - Replace rendered math with its source form (see [`normalize_math`])
 /// - Remove form elements
+/// - Remove elements matching `strip_selectors`
 ///
 /// This should be called BEFORE content extraction
-pub fn prep_document(html: &str) -> String {
-    let mut html = html.to_string();
+pub fn prep_document(html: &str, strip_selectors: &[String], math: MathNormalization) -> String {
+    // Math sources include <script> elements, so they go before scripts are removed
+    let mut html = normalize_math(html, math);
 
     let script_regex = regex::Regex::new(r"(?i)<script\b[^>]*>[\s\S]*?</script>").unwrap();
     html = script_regex.replace_all(&html, "").to_string();
@@ -848,6 +1994,7 @@ pub fn prep_document(html: &str) -> String {
     let font_close_regex = regex::Regex::new(r"</font>").unwrap();
     html = font_close_regex.replace_all(&html, "</span>").to_string();
 
+    html = unwrap_noscript_images(&html);
     let noscript_regex = regex::Regex::new(r"(?is)<noscript\b[^>]*>(.*?)</noscript>").unwrap();
     html = noscript_regex
         .replace_all(&html, |caps: &regex::Captures| {
@@ -863,13 +2010,234 @@ pub fn prep_document(html: &str) -> String {
     let form_regex = regex::Regex::new(r"(?i)<form\b[^>]*>[\s\S]*?</form>").unwrap();
     html = form_regex.replace_all(&html, "").to_string();
 
+    html = fix_lazy_images(&html);
+    html = normalize_code_blocks(&html);
+
+    if !strip_selectors.is_empty() {
+        let document = kuchikikiki::parse_html().one(html.as_str());
+        if remove_selected_nodes(&document, strip_selectors) {
+            html = serialize_node(&document, false);
+        }
+    }
+
     html
 }
 
+/// Remove elements matching any of `selectors` from extracted content
+///
+/// Returns the HTML unchanged when nothing matches.
+pub fn strip_selected_elements(html: &str, selectors: &[String]) -> String {
+    if selectors.is_empty() {
+        return html.to_string();
+    }
+
+    let document = kuchikikiki::parse_html().one(html);
+    let Ok(body) = document.select_first("body") else {
+        return html.to_string();
+    };
+    if remove_selected_nodes(body.as_node(), selectors) {
+        serialize_node(body.as_node(), true)
+    } else {
+        html.to_string()
+    }
+}
+
+/// Detach every element under `root` matching one of `selectors`, skipping invalid ones
+fn remove_selected_nodes(root: &NodeRef, selectors: &[String]) -> bool {
+    let mut removed = false;
+    for selector in selectors {
+        let Ok(matches) = root.select(selector) else {
+            continue;
+        };
+        for node in matches.map(|m| m.as_node().clone()).collect::<Vec<_>>() {
+            node.detach();
+            removed = true;
+        }
+    }
+    removed
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_apply_semantic_policy() {
+        let html = r#"<p>Text <time datetime="2024-03-01">March 1</time>.</p>
+            <aside class="pullquote">A memorable line.</aside>
+            <aside class="related">Related links</aside>
+            <figure><img src="a.jpg"><figcaption>Caption</figcaption></figure>"#;
+
+        let default = apply_semantic_policy(html, &SemanticElementPolicy::default());
+        assert!(default.contains(r#"<aside class="pullquote">A memorable line.</aside>"#));
+        assert!(!default.contains("Related links"));
+        assert!(default.contains("<figcaption>Caption</figcaption>"));
+
+        let policy = SemanticElementPolicy {
+            time: ElementPolicy::Unwrap,
+            pullquote: ElementPolicy::Unwrap,
+            figcaption: ElementPolicy::Drop,
+            ..Default::default()
+        };
+        let cleaned = apply_semantic_policy(html, &policy);
+        assert!(cleaned.contains("<p>Text March 1.</p>"));
+        assert!(cleaned.contains("A memorable line."));
+        assert!(!cleaned.contains("<aside"));
+        assert!(!cleaned.contains("Caption"));
+    }
+
+    #[test]
+    fn test_strip_selectors() {
+        let selectors = vec![
+            ".newsletter".to_string(),
+            "aside[data-affiliate]".to_string(),
+        ];
+        let html = r#"<html><body><article><p>Story text.</p>
+            <div class="newsletter"><p>Sign up for our newsletter!</p></div>
+            <aside data-affiliate="1">We may earn a commission.</aside></article></body></html>"#;
+
+        let prepped = prep_document(html, &selectors, MathNormalization::Off);
+        assert!(prepped.contains("Story text."));
+        assert!(!prepped.contains("newsletter"));
+        assert!(!prepped.contains("commission"));
+
+        let content = r#"<p>Story text.</p><div class="newsletter"><p>Sign up!</p></div>"#;
+        assert_eq!(
+            strip_selected_elements(content, &selectors),
+            "<p>Story text.</p>"
+        );
+        assert_eq!(strip_selected_elements(content, &[]), content);
+    }
+
+    #[test]
+    fn test_fix_lazy_images() {
+        let html = r#"<p><img src="data:image/gif;base64,R0lGODlhAQABAAAAACH5BAEKAAEALAAAAAABAAEAAAICTAEAOw==" data-src="/photos/a.jpg"></p>
+            <p><img class="lazyload" data-original="/photos/b.jpg" data-srcset="/photos/b.jpg 1x, /photos/b@2x.jpg 2x"></p>
+            <picture><source data-srcset="/photos/c.webp"><img src="data:image/gif;base64,R0lGOD"></picture>
+            <figure data-lazy-src="/photos/d.png"><figcaption>Caption</figcaption></figure>"#;
+
+        let fixed = fix_lazy_images(html);
+        assert!(!fixed.contains("R0lGOD"));
+        assert!(fixed.contains(r#"src="/photos/a.jpg""#));
+        assert!(fixed.contains(r#"src="/photos/b.jpg""#));
+        assert!(fixed.contains(r#"srcset="/photos/b.jpg 1x, /photos/b@2x.jpg 2x""#));
+        assert!(fixed.contains(r#"<source data-srcset="/photos/c.webp" srcset="/photos/c.webp">"#));
+        assert!(fixed.contains(r#"src="/photos/c.webp""#));
+        assert!(fixed.contains(r#"<img src="/photos/d.png"></figure>"#));
+
+        // Images that already load are left alone
+        let plain = r#"<p><img src="/photos/e.jpg" data-src="/photos/other.jpg"></p>"#;
+        assert_eq!(fix_lazy_images(plain), plain);
+    }
+
+    #[test]
+    fn test_unwrap_noscript_images() {
+        let html = r#"<html><body><picture><source srcset="/300.jpg" media="(max-width: 575px)">
+            <img class="lead" srcset="/300.jpg" alt="" itemprop="contentUrl"><noscript>
+            <img class="lead" src="/300.jpg" alt=""></noscript></picture>
+            <p><img src="/inline.png"> Text</p><noscript><p>Enable JavaScript</p></noscript>
+            </body></html>"#;
+
+        let unwrapped = unwrap_noscript_images(html);
+        assert_eq!(unwrapped.matches("<img").count(), 2);
+        assert!(!unwrapped.contains("contentUrl"));
+        let image = r#"<img class="lead" src="/300.jpg" alt="" srcset="/300.jpg">"#;
+        assert!(unwrapped.contains(image));
+
+        // Through prep_document the lead picture keeps a single image
+        let prepped = prep_document(html, &[], MathNormalization::Off);
+        let start = prepped.find("<picture").unwrap();
+        let picture = &prepped[start..prepped.find("</picture>").unwrap()];
+        assert_eq!(picture.matches("<img").count(), 1);
+        assert!(prepped.contains("Enable JavaScript"));
+    }
+
+    #[test]
+    fn test_fix_relative_urls_and_collapse_srcset() {
+        let html = r##"<p><a href="/about">About</a> <a href="#notes">Notes</a>
+            <a href="https://other.example/">Other</a></p>
+            <img src="photo.jpg?w=1&amp;h=2" srcset="photo.jpg 1x, photo@2x.jpg 2x">
+            <picture><source media="(min-width: 1000px)" srcset="/wide.webp">
+            <source type="image/avif" srcset="/narrow.avif"><source srcset="/narrow.jpg 1x, /narrow@2x.jpg 2x">
+            <img src="/fallback.jpg" alt="Fallback"></picture>"##;
+
+        let fixed = fix_relative_urls_in_html(html, "https://example.com/news/story");
+        assert!(fixed.contains(r#"<a href="https://example.com/about">"#));
+        assert!(fixed.contains(r##"<a href="#notes">"##));
+        assert!(fixed.contains(r#"<a href="https://other.example/">"#));
+        assert!(fixed.contains(r#"src="https://example.com/news/photo.jpg?w=1&amp;h=2""#));
+        assert!(fixed.contains(
+            r#"srcset="https://example.com/news/photo.jpg 1x, https://example.com/news/photo@2x.jpg 2x""#
+        ));
+        assert!(fixed.contains(r#"srcset="https://example.com/wide.webp""#));
+
+        assert_eq!(collapse_srcset(&fixed, SrcsetSelection::Keep), fixed);
+        let collapsed = collapse_srcset(&fixed, SrcsetSelection::Density(2.0));
+        assert!(!collapsed.contains("srcset"));
+        assert!(!collapsed.contains("picture"));
+        assert!(collapsed.contains(r#"<img src="https://example.com/news/photo@2x.jpg">"#));
+        assert!(
+            collapsed.contains(r#"<img src="https://example.com/narrow@2x.jpg" alt="Fallback">"#)
+        );
+
+        let wide = collapse_srcset(&fixed, SrcsetSelection::Width(1200));
+        assert!(wide.contains(r#"<img src="https://example.com/news/photo.jpg">"#));
+        assert!(wide.contains(r#"<img src="https://example.com/wide.webp" alt="Fallback">"#));
+    }
+
+    #[test]
+    fn test_normalize_code_blocks() {
+        let html = r#"<html><body>
+            <div class="highlight highlight-source-js"><pre>let a = 1;</pre></div>
+            <pre><code class="hljs py">x = 1</code></pre>
+            <pre class="lang-rb"><code>puts 1</code></pre>
+            <div class="lang-en"><pre>plain text</pre></div>
+            <div class="highlight-python notranslate"><table class="highlighttable"><tr>
+                <td class="linenos"><div class="linenodiv"><pre>1</pre></div></td>
+                <td class="code"><div class="highlight"><pre>def f():
+    return 1</pre></div></td>
+            </tr></table></div>
+            </body></html>"#;
+
+        let normalized = normalize_code_blocks(html);
+        assert!(normalized.contains(r#"<pre class="language-javascript">let a = 1;</pre>"#));
+        assert!(normalized.contains(r#"<code class="hljs py language-python">x = 1</code>"#));
+        assert!(normalized.contains(r#"<pre class="lang-rb"><code class="language-ruby">"#));
+        assert!(normalized.contains("<pre>plain text</pre>"));
+        assert!(!normalized.contains("linenos"));
+        assert!(normalized.contains("<pre class=\"language-python\">def f():\n    return 1</pre>"));
+
+        let plain = "<p>No code here.</p>";
+        assert_eq!(normalize_code_blocks(plain), plain);
+    }
+
+    #[test]
+    fn test_normalize_math() {
+        let html = r#"<html><body>
+            <p>KaTeX <span class="katex"><span class="katex-mathml"><math><semantics><mrow><mi>x</mi></mrow><annotation encoding="application/x-tex">x^2</annotation></semantics></math></span><span class="katex-html" aria-hidden="true">x2</span></span></p>
+            <p>MathJax 2 <span class="MathJax_Preview">a+b</span><span class="MathJax" id="MathJax-Element-1-Frame"><nobr>a+b</nobr></span><script type="math/tex" id="MathJax-Element-1">a+b</script></p>
+            <p>MathJax 3 <mjx-container class="MathJax" jax="CHTML" display="true"><mjx-math>y</mjx-math><mjx-assistive-mml><math display="block"><mi>y</mi></math></mjx-assistive-mml></mjx-container></p>
+            <p>MediaWiki <span class="mwe-math-element"><span class="mwe-math-mathml-inline mwe-math-mathml-a11y" style="display: none;"><math alttext="{\displaystyle \lambda }"><mi>λ</mi></math></span><img class="mwe-math-fallback-image-inline" alt="{\displaystyle \lambda }" src="l.svg"></span></p>
+            </body></html>"#;
+
+        assert_eq!(normalize_math(html, MathNormalization::Off), html);
+
+        let tex = normalize_math(html, MathNormalization::Tex);
+        assert!(tex.contains(r#"KaTeX <span class="math inline">\(x^2\)</span></p>"#));
+        assert!(tex.contains(r#"MathJax 2 <span class="math inline">\(a+b\)</span></p>"#));
+        assert!(tex.contains(r#"MathJax 3 <math display="block"><mi>y</mi></math></p>"#));
+        assert!(tex.contains(r#"MediaWiki <span class="math inline">\(\lambda\)</span></p>"#));
+        assert!(!tex.contains(r#"class="katex"#) && !tex.contains(r#"class="MathJax"#));
+        assert!(!tex.contains("<mjx-") && !tex.contains("mwe-math"));
+
+        let mathml = normalize_math(html, MathNormalization::MathMl);
+        assert!(mathml.contains(r#"KaTeX <math alttext="x^2"><semantics>"#));
+        assert!(mathml.contains(r#"MathJax 2 <span class="math inline">\(a+b\)</span></p>"#));
+        assert!(mathml
+            .contains(r#"MediaWiki <math alttext="{\displaystyle \lambda }"><mi>λ</mi></math>"#));
+        assert!(!mathml.contains("x2</span>"));
+    }
+
     #[test]
     fn test_remove_nav_like_sections() {
         let html = r#"
@@ -888,6 +2256,28 @@ mod tests {
         assert!(!cleaned.contains("sidebar"));
     }
 
+    #[test]
+    fn test_mark_block_directions() {
+        let html = r#"<div><p>An English paragraph.</p><blockquote><p>שלום עולם</p></blockquote><p dir="ltr">مرحبا</p><div dir="rtl"><p>Lorem ipsum</p></div></div>"#;
+
+        let marked = mark_block_directions(html, "ltr");
+        assert!(marked.contains(r#"<blockquote dir="rtl"><p>שלום עולם</p></blockquote>"#));
+        assert!(marked.contains("<p>An English paragraph.</p>"));
+        assert!(marked.contains(r#"<p dir="ltr">مرحبا</p>"#));
+        assert!(marked.contains(r#"<div dir="rtl"><p>Lorem ipsum</p></div>"#));
+
+        let plain = "<div><p>Only English here.</p></div>";
+        assert_eq!(mark_block_directions(plain, "ltr"), plain);
+
+        // Latin blocks on a right-to-left page are left alone; only blocks that
+        // differ from their nearest `dir` are marked
+        let rtl =
+            r#"<div><p>שלום עולם</p><p>Hello world</p><div dir="ltr"><p>مرحبا</p></div></div>"#;
+        let marked = mark_block_directions(rtl, "rtl");
+        assert!(marked.contains("<p>שלום עולם</p><p>Hello world</p>"));
+        assert!(marked.contains(r#"<div dir="ltr"><p dir="rtl">مرحبا</p></div>"#));
+    }
+
     #[test]
     fn test_remove_conditionally_removes_nav_table() {
         let html = r##"
@@ -37,6 +37,7 @@ pub struct RegexPatterns {
     pub json_ld_article_types: Regex,
     pub ad_words: Regex,
     pub loading_words: Regex,
+    pub paywall: Regex,
 }
 
 impl RegexPatterns {
@@ -78,6 +79,9 @@ impl RegexPatterns {
             loading_words: Regex::new(
                 r"(?iu)^((loading|正在加载|Загрузка|chargement|cargando)(…|\.\.\.)?)$"
             ).unwrap(),
+            paywall: Regex::new(
+                r"(?i)paywall|pay-wall|regwall|subscriber-only|subscribers-only|subscription-wall|premium-content|content-locked|article-locked|locked-content|meter-wall|metered-content|tp-container-inner|piano-offer"
+            ).unwrap(),
         }
     }
 }
@@ -2,17 +2,51 @@
 
 use crate::constants::{ParseFlags, DEFAULT_TAGS_TO_SCORE, REGEXPS};
 use crate::error::Result;
-use crate::options::ReadabilityOptions;
+use crate::options::{ElementPolicy, ReadabilityOptions, SemanticElementPolicy};
 use crate::{dom_utils, scoring};
+use once_cell::sync::Lazy;
 use scraper::{ElementRef, Html, Selector};
 use std::collections::HashMap;
 use v_htmlescape::escape;
 
+/// Article content extracted from the best candidate
+#[derive(Debug, Clone, Default)]
+pub struct ExtractedArticle {
+    /// Aggregated HTML of the best candidate and its qualifying siblings
+    pub content: String,
+    /// A paywall element sits next to the best candidate and the extract is short,
+    /// so the content is likely only a teaser
+    pub paywall_detected: bool,
+    /// Text direction declared on the best candidate or its closest ancestor
+    pub dir: Option<String>,
+    /// Text of the article heading found inside or just above the content
+    pub heading: Option<String>,
+    /// How far the best candidate's score is ahead of the strongest unrelated
+    /// candidate, from 0.0 (tied) to 1.0 (unchallenged)
+    pub score_margin: f64,
+    /// The best candidate is, or sits inside, an `<article>` or `<main>` element
+    pub semantic_root: bool,
+    /// Overall confidence in the extraction, from 0.0 to 1.0 (see [`extraction_confidence`])
+    pub confidence: f32,
+}
+
+/// One of the alternative extractions collected by [`grab_candidates`]
+#[derive(Debug, Clone)]
+pub struct CandidateExtraction {
+    pub article: ExtractedArticle,
+    /// Content score of the candidate element, after the link density penalty
+    pub score: f64,
+    pub text_length: usize,
+    /// Flags of the attempt that produced the extraction
+    pub flags: ParseFlags,
+}
+
 /// Represents an extraction attempt
 #[derive(Debug, Clone)]
 struct Attempt {
-    content: String,
+    article: ExtractedArticle,
     text_length: usize,
+    attempt_num: usize,
 }
 
 /// Main content extraction algorithm with retry logic
@@ -20,7 +54,14 @@ struct Attempt {
 /// Implements Mozilla's Readability algorithm with adaptive flag removal.
 /// If extraction fails with strict settings, retries with progressively
 /// looser criteria until content is found or all options are exhausted.
-pub fn grab_article(document: &Html, options: &ReadabilityOptions) -> Result<Option<String>> {
+pub fn grab_article(
+    document: &Html,
+    options: &ReadabilityOptions,
+) -> Result<Option<ExtractedArticle>> {
+    if let Some(article) = extract_selected_content(document, options)? {
+        return Ok(Some(article));
+    }
+
     let mut attempts = Vec::new();
     let mut flags =
         ParseFlags::STRIP_UNLIKELYS | ParseFlags::WEIGHT_CLASSES | ParseFlags::CLEAN_CONDITIONALLY;
@@ -30,18 +71,24 @@ pub fn grab_article(document: &Html, options: &ReadabilityOptions) -> Result<Opt
     for attempt_num in 0..4 {
         let attempt_result = try_extract_with_flags(document, options, flags)?;
 
-        if let Some(content) = attempt_result {
-            let text_length = extract_text_length(&content);
+        if let Some(article) = attempt_result {
+            let text_length = extract_text_length(&article.content);
 
             // Check if we have enough content
             if text_length >= options.char_threshold {
-                return Ok(Some(content));
+                return Ok(Some(with_confidence(
+                    article,
+                    attempt_num,
+                    text_length,
+                    options,
+                )));
             }
 
             // Save this attempt for potential fallback
             attempts.push(Attempt {
-                content,
+                article,
                 text_length,
+                attempt_num,
             });
         }
 
@@ -56,21 +103,238 @@ pub fn grab_article(document: &Html, options: &ReadabilityOptions) -> Result<Opt
 
     // No successful extraction with threshold, return longest attempt
     if !attempts.is_empty() {
+        #[allow(clippy::unnecessary_sort_by)]
         attempts.sort_by(|a, b| b.text_length.cmp(&a.text_length));
         if attempts[0].text_length > 0 {
-            return Ok(Some(attempts[0].content.clone()));
+            let attempt = attempts.swap_remove(0);
+            return Ok(Some(with_confidence(
+                attempt.article,
+                attempt.attempt_num,
+                attempt.text_length,
+                options,
+            )));
         }
     }
 
     Ok(None)
 }
 
+fn with_confidence(
+    mut article: ExtractedArticle,
+    attempt_num: usize,
+    text_length: usize,
+    options: &ReadabilityOptions,
+) -> ExtractedArticle {
+    article.confidence = extraction_confidence(&article, attempt_num, text_length, options);
+    article
+}
+
+/// Combine the extraction signals into a confidence between 0.0 and 1.0
+///
+/// Weighs, from most to least important, the best candidate's score margin, how
+/// many flags had to be relaxed (`attempt_num`), the link density of the extract
+/// and whether it came from a semantic `<article>`/`<main>` element. The result
+/// is then scaled down for text shorter than `char_threshold`.
+pub fn extraction_confidence(
+    article: &ExtractedArticle,
+    attempt_num: usize,
+    text_length: usize,
+    options: &ReadabilityOptions,
+) -> f32 {
+    let length = if options.char_threshold > 0 {
+        (text_length as f64 / options.char_threshold as f64).min(1.0)
+    } else {
+        1.0
+    };
+    let relaxation = match attempt_num {
+        0 => 1.0,
+        1 => 0.7,
+        2 => 0.45,
+        _ => 0.25,
+    };
+    let link_density =
+        dom_utils::get_link_density(Html::parse_fragment(&article.content).root_element());
+    let links = 1.0 - (link_density / 0.5).min(1.0);
+    let semantic = if article.semantic_root { 1.0 } else { 0.5 };
+
+    let signals = 0.35 * article.score_margin.clamp(0.0, 1.0)
+        + 0.3 * relaxation
+        + 0.2 * links
+        + 0.15 * semantic;
+    (signals * length.sqrt()).clamp(0.0, 1.0) as f32
+}
+
+/// Collect up to `n` distinct extractions across all flag combinations
+///
+/// Each attempt contributes its best candidate, as [`grab_article`] would pick it,
+/// followed by the other top-scored elements; extractions identical to an earlier
+/// one are skipped. The first extraction is the one [`grab_article`] returns when
+/// no content selector applies, the rest follow by descending score.
+pub fn grab_candidates(
+    document: &Html,
+    options: &ReadabilityOptions,
+    n: usize,
+) -> Result<Vec<CandidateExtraction>> {
+    if n == 0 {
+        return Ok(Vec::new());
+    }
+
+    // Extractions in the order found, flagged when they are an attempt's best candidate
+    let mut extractions: Vec<(bool, CandidateExtraction)> = Vec::new();
+
+    let mut flags =
+        ParseFlags::STRIP_UNLIKELYS | ParseFlags::WEIGHT_CLASSES | ParseFlags::CLEAN_CONDITIONALLY;
+
+    for attempt_num in 0..4 {
+        let candidates = find_candidates(document, options, flags)?;
+        if !candidates.is_empty() {
+            let mut scores = score_candidates(document, candidates, options, flags);
+            apply_link_density_penalty(document, &mut scores);
+
+            let mut ranked: Vec<(&String, f64)> =
+                scores.iter().map(|(id, score)| (id, *score)).collect();
+            ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+            let top_score = ranked.first().map_or(0.0, |(_, score)| *score);
+
+            let best_id = find_best_candidate(document, &scores, options);
+            let mut ids: Vec<String> = best_id.iter().cloned().collect();
+            for (id, _) in ranked.iter().take(options.nb_top_candidates.max(n)) {
+                if !ids.contains(id) {
+                    ids.push((*id).clone());
+                }
+            }
+
+            for id in ids {
+                let is_best = best_id.as_ref() == Some(&id);
+                let score = scores.get(&id).copied().unwrap_or(top_score);
+                let article = extract_article_content(document, id, &scores, options)?;
+                let text_length = extract_text_length(&article.content);
+                if text_length == 0 {
+                    continue;
+                }
+
+                let known = extractions
+                    .iter_mut()
+                    .find(|(_, other)| other.article.content == article.content);
+                match known {
+                    // Content already found still counts as this attempt's pick
+                    Some((known_is_best, _)) => *known_is_best |= is_best,
+                    None => extractions.push((
+                        is_best,
+                        CandidateExtraction {
+                            article,
+                            score,
+                            text_length,
+                            flags,
+                        },
+                    )),
+                }
+            }
+        }
+
+        match attempt_num {
+            0 => flags.remove(ParseFlags::STRIP_UNLIKELYS),
+            1 => flags.remove(ParseFlags::WEIGHT_CLASSES),
+            2 => flags.remove(ParseFlags::CLEAN_CONDITIONALLY),
+            _ => break,
+        }
+    }
+
+    // The pick grab_article makes: the first best candidate reaching the threshold,
+    // else the longest one
+    let best_candidates = extractions
+        .iter()
+        .enumerate()
+        .filter(|(_, (is_best, _))| *is_best);
+    let pick = best_candidates
+        .clone()
+        .find(|(_, (_, extraction))| extraction.text_length >= options.char_threshold)
+        .or_else(|| {
+            best_candidates.max_by_key(|(index, (_, extraction))| {
+                (extraction.text_length, std::cmp::Reverse(*index))
+            })
+        })
+        .map(|(index, _)| index);
+
+    let mut ordered = Vec::with_capacity(extractions.len());
+    if let Some(index) = pick {
+        ordered.push(extractions.remove(index).1);
+    }
+    let mut rest: Vec<CandidateExtraction> = extractions
+        .into_iter()
+        .map(|(_, extraction)| extraction)
+        .collect();
+    rest.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+    ordered.extend(rest);
+    ordered.truncate(n);
+    Ok(ordered)
+}
+
+/// Use the first element matched by `options.content_selectors` as the best candidate
+///
+/// Selectors are tried in order and elements without text are skipped. Sibling
+/// aggregation only runs with `options.content_selector_siblings`, scoring the
+/// document first so siblings are judged as they would be for a scored candidate.
+fn extract_selected_content(
+    document: &Html,
+    options: &ReadabilityOptions,
+) -> Result<Option<ExtractedArticle>> {
+    for selector in &options.content_selectors {
+        let Ok(selector) = Selector::parse(selector) else {
+            continue;
+        };
+        let Some(root) = document
+            .select(&selector)
+            .find(|element| !dom_utils::get_inner_text(*element, true).is_empty())
+        else {
+            continue;
+        };
+
+        if options.content_selector_siblings {
+            let flags = ParseFlags::STRIP_UNLIKELYS
+                | ParseFlags::WEIGHT_CLASSES
+                | ParseFlags::CLEAN_CONDITIONALLY;
+            let candidates = find_candidates(document, options, flags)?;
+            let mut scores = score_candidates(document, candidates, options, flags);
+            apply_link_density_penalty(document, &mut scores);
+            let mut article =
+                extract_article_content(document, get_element_id(&root), &scores, options)?;
+            article.score_margin = 1.0;
+            article.semantic_root = true;
+            let text_length = extract_text_length(&article.content);
+            return Ok(Some(with_confidence(article, 0, text_length, options)));
+        }
+
+        let content = crate::cleaner::replace_brs(&element_to_html(root));
+        if content.trim().is_empty() {
+            continue;
+        }
+        let paywall_nearby = root
+            .descendants()
+            .filter_map(ElementRef::wrap)
+            .any(is_paywall_element);
+        let text_length = extract_text_length(&content);
+        let article = ExtractedArticle {
+            paywall_detected: paywall_nearby && text_length < options.char_threshold * 3,
+            content,
+            dir: dom_utils::get_inherited_direction(root),
+            heading: find_article_heading(&[root], root),
+            score_margin: 1.0,
+            semantic_root: true,
+            confidence: 0.0,
+        };
+        return Ok(Some(with_confidence(article, 0, text_length, options)));
+    }
+
+    Ok(None)
+}
+
 /// Try to extract article content with specific flags
 fn try_extract_with_flags(
     document: &Html,
     options: &ReadabilityOptions,
     flags: ParseFlags,
-) -> Result<Option<String>> {
+) -> Result<Option<ExtractedArticle>> {
     let candidates = find_candidates(document, options, flags)?;
     if candidates.is_empty() {
         return Ok(None);
@@ -80,8 +344,10 @@ fn try_extract_with_flags(
     apply_link_density_penalty(document, &mut scored_candidates);
 
     if let Some(best) = find_best_candidate(document, &scored_candidates, options) {
-        let content = extract_article_content(document, best, &scored_candidates, options)?;
-        return Ok(Some(content));
+        let margin = score_margin(document, &scored_candidates, &best);
+        let mut article = extract_article_content(document, best, &scored_candidates, options)?;
+        article.score_margin = margin;
+        return Ok(Some(article));
     }
 
     Ok(None)
@@ -627,14 +893,18 @@ fn promote_semantic_descendant(
 ///    - Score >= 20% of the best candidate's score, OR
 ///    - Are good paragraphs (low link density, decent text length)
 /// 4. Aggregate all content together
+///
+/// A paywall element (see [`is_paywall_element`]) inside the best candidate or
+/// among its siblings marks the extract as a likely teaser when the aggregated
+/// text is short.
 fn extract_article_content(
     document: &Html,
     best_candidate_id: String,
     all_scores: &HashMap<String, f64>,
-    _options: &ReadabilityOptions,
-) -> Result<String> {
+    options: &ReadabilityOptions,
+) -> Result<ExtractedArticle> {
     let Some(best_candidate) = find_element_by_id(document, &best_candidate_id) else {
-        return Ok(String::new());
+        return Ok(ExtractedArticle::default());
     };
 
     let best_score = all_scores.get(&best_candidate_id).copied().unwrap_or(0.0);
@@ -650,9 +920,28 @@ fn extract_article_content(
         // No parent, just return the best candidate
         let html = element_to_html(best_candidate);
         let html = crate::cleaner::replace_brs(&html);
-        return Ok(html);
+        let paywall_nearby = best_candidate
+            .descendants()
+            .filter_map(ElementRef::wrap)
+            .any(is_paywall_element);
+        let text_length = extract_text_length(&html);
+        return Ok(ExtractedArticle {
+            content: html,
+            paywall_detected: paywall_nearby && text_length < options.char_threshold * 3,
+            dir: dom_utils::get_inherited_direction(best_candidate),
+            heading: find_article_heading(&[best_candidate], best_candidate),
+            semantic_root: is_semantic_root(best_candidate),
+            ..Default::default()
+        });
     };
 
+    let mut included = Vec::new();
+    let mut paywall_nearby = best_candidate
+        .descendants()
+        .filter_map(ElementRef::wrap)
+        .any(is_paywall_element);
+    let mut text_length = 0;
+
     for child_node in parent.children() {
         let Some(sibling) = ElementRef::wrap(child_node) else {
             continue;
@@ -685,6 +974,7 @@ fn extract_article_content(
                 true
             } else {
                 should_keep_block_element(sibling, best_score)
+                    || should_keep_semantic_sibling(sibling, &options.semantic_elements)
             }
         };
 
@@ -693,12 +983,122 @@ fn extract_article_content(
             sibling_html = crate::cleaner::replace_brs(&sibling_html);
 
             if !sibling_html.trim().is_empty() {
+                text_length += dom_utils::get_inner_text(sibling, true).len();
                 article_content.push(sibling_html);
+                included.push(sibling);
+            }
+        } else if is_paywall_element(sibling) {
+            paywall_nearby = true;
+        }
+    }
+
+    Ok(ExtractedArticle {
+        content: article_content.join("\n"),
+        paywall_detected: paywall_nearby && text_length < options.char_threshold * 3,
+        dir: dom_utils::get_inherited_direction(best_candidate),
+        heading: find_article_heading(&included, best_candidate),
+        semantic_root: is_semantic_root(best_candidate),
+        ..Default::default()
+    })
+}
+
+/// Whether the element is, or sits inside, an `<article>` or `<main>` element
+fn is_semantic_root(element: ElementRef) -> bool {
+    std::iter::once(element)
+        .chain(element.ancestors().filter_map(ElementRef::wrap))
+        .any(|element| {
+            matches!(element.value().name(), "article" | "main")
+                || element.value().attr("role") == Some("main")
+        })
+}
+
+/// How far the best candidate's score is ahead of the strongest candidate that
+/// neither contains it nor sits inside it, as a fraction of the best score
+fn score_margin(document: &Html, scores: &HashMap<String, f64>, best_id: &str) -> f64 {
+    let Some(best) = find_element_by_id(document, best_id) else {
+        return 0.0;
+    };
+    let Some(best_score) = scores.get(best_id).copied().filter(|score| *score > 0.0) else {
+        return 0.0;
+    };
+
+    let mut ranked: Vec<(&String, f64)> = scores.iter().map(|(id, score)| (id, *score)).collect();
+    ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+    let runner_up = ranked
+        .into_iter()
+        .filter(|(id, _)| id.as_str() != best_id)
+        .take(20)
+        .find(|(id, _)| {
+            find_element_by_id(document, id).is_some_and(|element| {
+                !element.ancestors().any(|node| node.id() == best.id())
+                    && !best.ancestors().any(|node| node.id() == element.id())
+            })
+        })
+        .map_or(0.0, |(_, score)| score);
+
+    ((best_score - runner_up) / best_score).clamp(0.0, 1.0)
+}
+
+/// Find the article heading for title selection
+///
+/// Looks for an `<h1>` first and then an `<h2>`: inside the extracted content,
+/// then in the few elements just above it (preceding siblings of the best
+/// candidate and of up to two of its ancestors, nearest first).
+fn find_article_heading(included: &[ElementRef], best_candidate: ElementRef) -> Option<String> {
+    const ABOVE_LEVELS: usize = 3;
+    const ABOVE_SIBLINGS: usize = 3;
+    static H1_SELECTOR: Lazy<Selector> = Lazy::new(|| Selector::parse("h1").unwrap());
+    static H2_SELECTOR: Lazy<Selector> = Lazy::new(|| Selector::parse("h2").unwrap());
+
+    let heading_text = |element: ElementRef| {
+        let text = dom_utils::get_inner_text(element, true);
+        (!text.is_empty() && text.len() <= 300).then_some(text)
+    };
+
+    for (tag, selector) in [("h1", &*H1_SELECTOR), ("h2", &*H2_SELECTOR)] {
+        let inside = included.iter().find_map(|element| {
+            std::iter::once(*element)
+                .filter(|el| el.value().name() == tag)
+                .chain(element.select(selector))
+                .find_map(heading_text)
+        });
+        if inside.is_some() {
+            return inside;
+        }
+
+        let levels = std::iter::once(best_candidate)
+            .chain(best_candidate.ancestors().filter_map(ElementRef::wrap))
+            .take(ABOVE_LEVELS);
+        for level in levels {
+            let above = level
+                .prev_siblings()
+                .filter_map(ElementRef::wrap)
+                .take(ABOVE_SIBLINGS)
+                .find_map(|sibling| {
+                    if sibling.value().name() == tag {
+                        return heading_text(sibling);
+                    }
+                    sibling.select(selector).last().and_then(heading_text)
+                });
+            if above.is_some() {
+                return above;
             }
         }
     }
 
-    Ok(article_content.join("\n"))
+    None
+}
+
+/// Check if an element is a paywall, subscription prompt or locked-content container
+///
+/// Elements are matched on their class and id, plus the `data-paywall` attribute
+/// some publishers use instead.
+fn is_paywall_element(element: ElementRef) -> bool {
+    let class = element.value().attr("class").unwrap_or("");
+    let id = element.value().attr("id").unwrap_or("");
+    let match_string = format!("{class} {id}");
+
+    REGEXPS.paywall.is_match(&match_string) || element.value().attr("data-paywall").is_some()
 }
 
 /// Check if a sibling element is a "good paragraph" worth including
@@ -788,6 +1188,37 @@ fn should_keep_block_element(element: ElementRef, best_score: f64) -> bool {
     }
 }
 
+/// Determine whether a semantic sibling belongs with the article during aggregation.
+///
+/// Figures with media or a caption, pull quotes, definition lists and `<details>`
+/// carry little scorable text but are part of the article; they are kept unless
+/// their policy drops them anyway.
+fn should_keep_semantic_sibling(element: ElementRef, policy: &SemanticElementPolicy) -> bool {
+    use scraper::Selector;
+    let tag = element.value().name();
+    let text_length = dom_utils::get_inner_text(element, false).len();
+    let class = element.value().attr("class").unwrap_or("");
+    let id = element.value().attr("id").unwrap_or("");
+
+    match tag {
+        "figure" if policy.figure != ElementPolicy::Drop => {
+            let media_selector =
+                Selector::parse("img, picture, video, audio, svg, iframe, figcaption").unwrap();
+            element.select(&media_selector).next().is_some()
+        }
+        "aside" | "blockquote" if crate::cleaner::is_pullquote(&format!("{class} {id}")) => {
+            policy.pullquote != ElementPolicy::Drop && text_length > 0
+        }
+        "dl" if policy.dl != ElementPolicy::Drop => {
+            text_length > 80 && dom_utils::get_link_density(element) < 0.35
+        }
+        "details" if policy.details != ElementPolicy::Drop => {
+            text_length > 80 && dom_utils::get_link_density(element) < 0.35
+        }
+        _ => false,
+    }
+}
+
 /// Detects whether text contains a sentence-ending period followed by whitespace or end.
 fn has_sentence_boundary(text: &str) -> bool {
     let mut chars = text.chars().peekable();
@@ -992,7 +1423,7 @@ mod tests {
         let content = result.unwrap();
         assert!(content.is_some());
 
-        let content_html = content.unwrap();
+        let content_html = content.unwrap().content;
         assert!(content_html.contains("first paragraph"));
     }
 
@@ -1016,6 +1447,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::len_zero)]
     fn test_candidate_scoring() {
         let html = r#"
             <html>
@@ -1072,7 +1504,7 @@ mod tests {
         let content = result.unwrap();
         assert!(content.is_some());
 
-        let content_html = content.unwrap();
+        let content_html = content.unwrap().content;
 
         assert!(content_html.contains("first paragraph"));
         assert!(content_html.contains("second paragraph"));
@@ -1081,6 +1513,102 @@ mod tests {
         // but we should have at least the first three
     }
 
+    #[test]
+    fn test_paywall_next_to_teaser() {
+        let html = r#"
+            <html>
+                <body>
+                    <div class="story">
+                        <div class="story-body">
+                            <p>The council voted on Tuesday to approve the new transit plan, which has been debated for years.</p>
+                            <p>Supporters said the plan would cut commute times across the city by a significant margin.</p>
+                        </div>
+                        <div class="paywall-prompt">
+                            <p>Subscribe to keep reading.</p>
+                        </div>
+                    </div>
+                </body>
+            </html>
+        "#;
+
+        let document = Html::parse_document(html);
+        let options = ReadabilityOptions::builder().char_threshold(100).build();
+
+        let article = grab_article(&document, &options).unwrap().unwrap();
+        assert!(article.content.contains("transit plan"));
+        assert!(article.paywall_detected);
+
+        let html = html.replace("paywall-prompt", "newsletter-prompt");
+        let document = Html::parse_document(&html);
+        let article = grab_article(&document, &options).unwrap().unwrap();
+        assert!(!article.paywall_detected);
+    }
+
+    #[test]
+    fn test_content_selector_overrides_scoring() {
+        let html = r#"<html><body>
+            <div class="widget"><p>A long promotional paragraph that the scoring would happily pick, full of words, commas, and more words, to win.</p>
+            <p>Another long promotional paragraph with plenty of commas, words, and sentences to score highly here.</p></div>
+            <div class="story"><p>The short story text.</p></div>
+            <div class="story-extra"><p>Related reading.</p></div>
+        </body></html>"#;
+        let document = Html::parse_document(html);
+        let options = ReadabilityOptions::builder()
+            .content_selector(".missing")
+            .content_selector(".story")
+            .build();
+
+        let article = grab_article(&document, &options).unwrap().unwrap();
+        assert!(article.content.contains("The short story text."));
+        assert!(!article.content.contains("promotional"));
+        assert!(!article.content.contains("Related reading"));
+    }
+
+    #[test]
+    fn test_grab_candidates() {
+        let story = "The council approved the new bridge on Monday, ending years of debate. ";
+        let widget = "Subscribe today, and get more stories, newsletters, and offers delivered. ";
+        let (story, widget) = (story.repeat(8), widget.repeat(3));
+        let html = format!(
+            r#"<html><body>
+            <div id="main"><p>{story}</p><p>{story}</p></div>
+            <div id="aside"><p>{widget}</p><p>{widget}</p></div>
+            </body></html>"#
+        );
+        let document = Html::parse_document(&html);
+        let options = ReadabilityOptions::default();
+
+        let extractions = grab_candidates(&document, &options, 3).unwrap();
+        let best = grab_article(&document, &options).unwrap().unwrap();
+        assert!(extractions.len() >= 2);
+        assert_eq!(extractions[0].article.content, best.content);
+        assert!(extractions[1..]
+            .iter()
+            .any(|extraction| extraction.article.content.contains("Subscribe today")));
+        assert!(grab_candidates(&document, &options, 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_extraction_confidence() {
+        let paragraph =
+            "<p>The council approved the new bridge on Monday, ending years of debate.</p>";
+        let html = format!(
+            "<html><body><article>{}</article></body></html>",
+            paragraph.repeat(10)
+        );
+        let article = grab_article(&Html::parse_document(&html), &ReadabilityOptions::default())
+            .unwrap()
+            .unwrap();
+        assert!(article.semantic_root);
+        assert!(article.confidence > 0.8, "{}", article.confidence);
+
+        let html = format!("<html><body><div>{paragraph}</div></body></html>");
+        let short = grab_article(&Html::parse_document(&html), &ReadabilityOptions::default())
+            .unwrap()
+            .unwrap();
+        assert!(short.confidence < 0.5, "{}", short.confidence);
+    }
+
     #[test]
     fn test_html_escape() {
         let html = r#"
@@ -1108,7 +1636,7 @@ mod tests {
         let content = result.unwrap();
         assert!(content.is_some());
 
-        let content_html = content.unwrap();
+        let content_html = content.unwrap().content;
         assert!(!content_html.contains("<script>"));
         assert!(content_html.contains("&lt;script&gt;"));
     }
@@ -0,0 +1,292 @@
+//! Comment counts and discussion links, gathered without keeping the comments.
+
+use crate::article::{CommentSystem, Discussion};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use scraper::{ElementRef, Html, Selector};
+use serde_json::Value;
+
+/// Read `commentCount`, a `CommentAction` interaction statistic and `discussionUrl`
+/// from a Schema.org article item
+pub fn extract_schema_discussion(item: &Value) -> Option<Discussion> {
+    let statistic_count = || {
+        let statistics = match item.get("interactionStatistic")? {
+            Value::Array(statistics) => statistics.iter().collect(),
+            statistic => vec![statistic],
+        };
+        statistics.into_iter().find_map(|statistic| {
+            let interaction = match statistic.get("interactionType")? {
+                Value::String(kind) => kind.as_str(),
+                kind => kind.get("@type")?.as_str()?,
+            };
+            if !interaction.ends_with("CommentAction") {
+                return None;
+            }
+            statistic.get("userInteractionCount").and_then(schema_count)
+        })
+    };
+
+    let discussion = Discussion {
+        comment_count: item
+            .get("commentCount")
+            .and_then(schema_count)
+            .or_else(statistic_count),
+        url: item
+            .get("discussionUrl")
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+            .map(str::to_string),
+        system: None,
+    };
+    (discussion != Discussion::default()).then_some(discussion)
+}
+
+/// Detect the comment system, a comment count and a link to the thread in the page
+///
+/// Runs on the original document, before comment sections are removed from the content.
+/// Counts and links are only read from the comments section and the article itself, so
+/// "most discussed" lists in sidebars are ignored.
+pub fn extract_document_discussion(document: &Html) -> Option<Discussion> {
+    static COUNT_SELECTOR: Lazy<Selector> = Lazy::new(|| {
+        Selector::parse(
+            ".comment-count, .comments-count, .disqus-comment-count, [class*='comment-count'], \
+             [class*='comments-count'], [class*='commentcount'], a[href$='#comments'], \
+             a[href$='#disqus_thread'], a[href$='#respond']",
+        )
+        .unwrap()
+    });
+    static LINK_SELECTOR: Lazy<Selector> = Lazy::new(|| {
+        Selector::parse(
+            "link[rel~='replies'][href], a[href$='#comments'], a[href$='#disqus_thread'], \
+             a[href$='#coral_thread']",
+        )
+        .unwrap()
+    });
+
+    let scoped = document.select(&SCOPE_SELECTOR).next().is_some();
+    let count = document
+        .select(&COUNT_SELECTOR)
+        .filter(|element| in_discussion_scope(*element, scoped))
+        .map(|element| element.text().collect::<String>())
+        .filter(|text| text.trim().chars().count() <= 40)
+        .find_map(|text| parse_comment_count(&text));
+    let url = document
+        .select(&LINK_SELECTOR)
+        .filter(|element| in_discussion_scope(*element, scoped))
+        .filter_map(|element| element.value().attr("href"))
+        .map(str::trim)
+        .find(|href| !href.is_empty() && !href.starts_with('#'))
+        .map(str::to_string);
+
+    let discussion = Discussion {
+        comment_count: count,
+        url,
+        system: detect_comment_system(document),
+    };
+    (discussion != Discussion::default()).then_some(discussion)
+}
+
+/// The article and its comments section, where this page's own counts and links live
+static SCOPE_SELECTOR: Lazy<Selector> = Lazy::new(|| {
+    Selector::parse(
+        "article, main, [role='main'], [itemprop~='articleBody'], #comments, .comments, \
+         .comments-area, .comment-list, #respond, #disqus_thread, #coral_thread",
+    )
+    .unwrap()
+});
+
+/// Whether a count or link belongs to the article's discussion
+///
+/// The nearest of its ancestors decides: sidebars, navigation and "popular" widgets
+/// exclude it, the article or comments section include it. Pages with neither only
+/// exclude the sidebars.
+fn in_discussion_scope(element: ElementRef, scoped: bool) -> bool {
+    static SIDEBAR_REGEX: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"(?i)sidebar|widget|popular|trending|most-|related|recommend").unwrap()
+    });
+
+    for ancestor in element.ancestors().filter_map(ElementRef::wrap) {
+        let el = ancestor.value();
+        // Page-wide classes such as "has-sidebar" say nothing about the element
+        if el.name() == "body" {
+            break;
+        }
+        let class_and_id = format!(
+            "{} {}",
+            el.attr("class").unwrap_or_default(),
+            el.attr("id").unwrap_or_default()
+        );
+        if matches!(el.name(), "aside" | "nav") || SIDEBAR_REGEX.is_match(&class_and_id) {
+            return false;
+        }
+        if SCOPE_SELECTOR.matches(&ancestor) {
+            return true;
+        }
+    }
+    !scoped
+}
+
+/// Fill the gaps in the structured data with what the page shows
+pub fn merge_discussion(
+    schema: Option<Discussion>,
+    document: Option<Discussion>,
+) -> Option<Discussion> {
+    match (schema, document) {
+        (Some(schema), Some(document)) => Some(Discussion {
+            comment_count: schema.comment_count.or(document.comment_count),
+            url: schema.url.or(document.url),
+            system: schema.system.or(document.system),
+        }),
+        (schema, document) => schema.or(document),
+    }
+}
+
+fn detect_comment_system(document: &Html) -> Option<CommentSystem> {
+    static DISQUS_SELECTOR: Lazy<Selector> = Lazy::new(|| {
+        Selector::parse(
+            "#disqus_thread, [data-disqus-identifier], [data-disqus-url], \
+             script[src*='disqus.com'], iframe[src*='disqus.com']",
+        )
+        .unwrap()
+    });
+    static CORAL_SELECTOR: Lazy<Selector> = Lazy::new(|| {
+        Selector::parse(
+            "#coral_thread, #coral-thread, [id^='coral_talk'], [data-coral-url], \
+             script[src*='coral']",
+        )
+        .unwrap()
+    });
+    static NATIVE_SELECTOR: Lazy<Selector> = Lazy::new(|| {
+        Selector::parse(
+            "#comments, .comments, .comment-list, .commentlist, #respond, \
+             form#commentform, [itemprop~='comment']",
+        )
+        .unwrap()
+    });
+    static SCRIPT_SELECTOR: Lazy<Selector> =
+        Lazy::new(|| Selector::parse("script:not([src])").unwrap());
+
+    if document.select(&DISQUS_SELECTOR).next().is_some() {
+        return Some(CommentSystem::Disqus);
+    }
+    if document.select(&CORAL_SELECTOR).next().is_some() {
+        return Some(CommentSystem::Coral);
+    }
+
+    for script in document.select(&SCRIPT_SELECTOR) {
+        let code = script.text().collect::<String>();
+        if code.contains("disqus_config") || code.contains(".disqus.com/") {
+            return Some(CommentSystem::Disqus);
+        }
+        if code.contains("Coral.createStreamEmbed") || code.contains("Coral.Talk") {
+            return Some(CommentSystem::Coral);
+        }
+    }
+
+    document
+        .select(&NATIVE_SELECTOR)
+        .next()
+        .map(|_| CommentSystem::Native)
+}
+
+/// Read a count written as a number or a numeric string
+fn schema_count(value: &Value) -> Option<u64> {
+    match value {
+        Value::Number(n) => n.as_u64().or_else(|| n.as_f64().map(|f| f as u64)),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Parse counts such as "12 Comments", "1,234" or "1.2K comments"
+fn parse_comment_count(text: &str) -> Option<u64> {
+    static COUNT_REGEX: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"(?i)(\d{1,3}(?:[,.\s]\d{3})+|\d+(?:[.,]\d+)?)\s*([km])?\b").unwrap()
+    });
+
+    let caps = COUNT_REGEX.captures(text)?;
+    let number = &caps[1];
+    match caps.get(2).map(|m| m.as_str().to_ascii_lowercase()) {
+        Some(suffix) => {
+            let value: f64 = number.replace(',', ".").parse().ok()?;
+            let scale = if suffix == "k" { 1_000.0 } else { 1_000_000.0 };
+            Some((value * scale).round() as u64)
+        }
+        None => number
+            .chars()
+            .filter(char::is_ascii_digit)
+            .collect::<String>()
+            .parse()
+            .ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_discussion() {
+        let item: Value = serde_json::json!({
+            "@type": "NewsArticle",
+            "discussionUrl": "https://example.com/story#comments",
+            "interactionStatistic": [
+                {"@type": "InteractionCounter",
+                 "interactionType": "https://schema.org/LikeAction",
+                 "userInteractionCount": 900},
+                {"@type": "InteractionCounter",
+                 "interactionType": {"@type": "CommentAction"},
+                 "userInteractionCount": "42"}
+            ]
+        });
+        let discussion = extract_schema_discussion(&item).unwrap();
+        assert_eq!(discussion.comment_count, Some(42));
+        assert_eq!(
+            discussion.url.as_deref(),
+            Some("https://example.com/story#comments")
+        );
+        assert!(extract_schema_discussion(&serde_json::json!({"@type": "Article"})).is_none());
+    }
+
+    #[test]
+    fn test_document_discussion() {
+        let html = r#"<html><body>
+            <article><p>Story</p><a href="/story#disqus_thread">1,204 Comments</a></article>
+            <div id="disqus_thread"></div>
+            </body></html>"#;
+        let discussion = extract_document_discussion(&Html::parse_document(html)).unwrap();
+        assert_eq!(discussion.comment_count, Some(1204));
+        assert_eq!(discussion.url.as_deref(), Some("/story#disqus_thread"));
+        assert_eq!(discussion.system, Some(CommentSystem::Disqus));
+
+        let html = r#"<html><body><article><p>Story</p></article>
+            <section id="comments"><span class="comment-count">3.4K comments</span></section>
+            </body></html>"#;
+        let discussion = extract_document_discussion(&Html::parse_document(html)).unwrap();
+        assert_eq!(discussion.comment_count, Some(3400));
+        assert_eq!(discussion.system, Some(CommentSystem::Native));
+    }
+
+    #[test]
+    fn test_document_discussion_ignores_sidebar() {
+        let html = r##"<html><body>
+            <aside class="most-discussed">
+              <a href="/other-story#comments">9,876 comments</a>
+              <span class="comment-count">512</span>
+            </aside>
+            <article><p>Story</p><a href="/story#comments">14 comments</a></article>
+            <section id="comments"></section>
+            </body></html>"##;
+        let discussion = extract_document_discussion(&Html::parse_document(html)).unwrap();
+        assert_eq!(discussion.comment_count, Some(14));
+        assert_eq!(discussion.url.as_deref(), Some("/story#comments"));
+
+        // Without article markup, sidebar counts are still skipped
+        let html = r#"<html><body class="has-sidebar">
+            <div class="sidebar"><a href="/other-story#comments">250 comments</a></div>
+            <div class="post"><p>Story</p></div>
+            </body></html>"#;
+        assert!(extract_document_discussion(&Html::parse_document(html)).is_none());
+    }
+}
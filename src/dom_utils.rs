@@ -185,7 +185,7 @@ pub fn has_child_block_element(element: ElementRef) -> bool {
 
 /// Extract text direction from document
 ///
-/// Checks for dir attribute on <html> element.
+/// Checks for a dir attribute on the <html> element, then on <body>.
 /// Returns "ltr", "rtl", "auto", or None.
 ///
 /// # Arguments
@@ -194,20 +194,134 @@ pub fn has_child_block_element(element: ElementRef) -> bool {
 /// # Returns
 /// The text direction if found
 pub fn get_article_direction(document: &Html) -> Option<String> {
-    if let Some(html_elem) = document.root_element().first_child() {
-        if let Some(node_ref) = ElementRef::wrap(html_elem) {
-            if node_ref.value().name() == "html" {
-                if let Some(dir) = node_ref.value().attr("dir") {
-                    let dir = dir.trim().to_lowercase();
-                    if dir == "ltr" || dir == "rtl" || dir == "auto" {
-                        return Some(dir);
-                    }
-                }
+    let html = document.root_element();
+    parse_dir_attribute(html).or_else(|| {
+        html.children()
+            .filter_map(ElementRef::wrap)
+            .find(|child| child.value().name() == "body")
+            .and_then(parse_dir_attribute)
+    })
+}
+
+/// Get the text direction an element inherits
+///
+/// Returns the `dir` attribute of the element itself or of its closest
+/// ancestor that declares one.
+pub fn get_inherited_direction(element: ElementRef) -> Option<String> {
+    std::iter::once(element)
+        .chain(element.ancestors().filter_map(ElementRef::wrap))
+        .find_map(parse_dir_attribute)
+}
+
+fn parse_dir_attribute(element: ElementRef) -> Option<String> {
+    let dir = element.value().attr("dir")?.trim().to_lowercase();
+    matches!(dir.as_str(), "ltr" | "rtl" | "auto").then_some(dir)
+}
+
+/// Detect text direction from the first strong directional character
+///
+/// Follows the Unicode bidi rule browsers apply for `dir="auto"`: letters from
+/// right-to-left scripts (Hebrew, Arabic, Syriac, Thaana, N'Ko, ...) mean "rtl",
+/// any other letter means "ltr". Returns None if the text has no letters.
+pub fn detect_text_direction(text: &str) -> Option<&'static str> {
+    text.chars()
+        .find(|c| c.is_alphabetic())
+        .map(|c| if is_rtl_char(c) { "rtl" } else { "ltr" })
+}
+
+fn is_rtl_char(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x0590..=0x08FF | 0xFB1D..=0xFDFF | 0xFE70..=0xFEFF | 0x10800..=0x10FFF | 0x1E800..=0x1EFFF
+    )
+}
+
+/// Get the base URL relative links in the document resolve against
+///
+/// This is the page URL joined with the first `<base href>`, matching how
+/// browsers resolve links. Falls back to the page URL when there is no usable
+/// `<base>` element.
+///
+/// # Arguments
+/// * `document` - The HTML document
+/// * `page_url` - The URL the document was loaded from
+pub fn get_document_base_url(document: &Html, page_url: &str) -> String {
+    let base_selector = Selector::parse("base[href]").unwrap();
+    document
+        .select(&base_selector)
+        .next()
+        .and_then(|base| base.value().attr("href"))
+        .and_then(|href| crate::utils::resolve_url(href, page_url))
+        .unwrap_or_else(|| page_url.to_string())
+}
+
+/// Plain text of extracted article content
+///
+/// MathML `<math>` elements are rendered by their TeX source (the `alttext` or a TeX
+/// annotation) as `\(...\)`, or `\[...\]` for display math, instead of the
+/// concatenated glyphs of the presentation markup.
+pub fn content_text(html: &str) -> String {
+    let fragment = Html::parse_fragment(html);
+    if !html.contains("<math") {
+        return fragment.root_element().text().collect();
+    }
+
+    let mut text = String::new();
+    push_content_text(fragment.root_element(), &mut text);
+    text
+}
+
+fn push_content_text(element: ElementRef, text: &mut String) {
+    for child in element.children() {
+        if let Some(node_text) = child.value().as_text() {
+            text.push_str(node_text);
+        } else if let Some(child) = ElementRef::wrap(child) {
+            if child.value().name() == "math" {
+                push_math_text(child, text);
+            } else {
+                push_content_text(child, text);
             }
         }
     }
+}
+
+fn push_math_text(math: ElementRef, text: &mut String) {
+    let is_annotation =
+        |element: &ElementRef| matches!(element.value().name(), "annotation" | "annotation-xml");
+    let tex = math
+        .value()
+        .attr("alttext")
+        .and_then(crate::cleaner::clean_tex)
+        .or_else(|| {
+            math.descendants()
+                .filter_map(ElementRef::wrap)
+                .find(|element| {
+                    is_annotation(element)
+                        && element.value().attr("encoding") == Some("application/x-tex")
+                })
+                .and_then(|annotation| {
+                    crate::cleaner::clean_tex(&annotation.text().collect::<String>())
+                })
+        });
 
-    None
+    match tex {
+        Some(tex) if math.value().attr("display") == Some("block") => {
+            text.push_str(&format!("\\[{tex}\\]"));
+        }
+        Some(tex) => text.push_str(&format!("\\({tex}\\)")),
+        None => {
+            for node in math.descendants() {
+                let in_annotation = node
+                    .ancestors()
+                    .filter_map(ElementRef::wrap)
+                    .take_while(|ancestor| ancestor.id() != math.id())
+                    .any(|ancestor| is_annotation(&ancestor));
+                if let (Some(node_text), false) = (node.value().as_text(), in_annotation) {
+                    text.push_str(node_text);
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -224,6 +338,58 @@ mod tests {
         assert_eq!(get_inner_text(elem, true), "Hello world");
     }
 
+    #[test]
+    fn test_text_direction() {
+        let html = Html::parse_document(
+            r#"<html><body dir="RTL"><div dir="ltr"><p>x</p></div><p>y</p></body></html>"#,
+        );
+        assert_eq!(get_article_direction(&html).as_deref(), Some("rtl"));
+
+        let p_sel = Selector::parse("p").unwrap();
+        let mut paragraphs = html.select(&p_sel);
+        assert_eq!(
+            get_inherited_direction(paragraphs.next().unwrap()).as_deref(),
+            Some("ltr")
+        );
+        assert_eq!(
+            get_inherited_direction(paragraphs.next().unwrap()).as_deref(),
+            Some("rtl")
+        );
+
+        assert_eq!(detect_text_direction("  123 שלום world"), Some("rtl"));
+        assert_eq!(detect_text_direction("« Bonjour »"), Some("ltr"));
+        assert_eq!(detect_text_direction("مرحبا"), Some("rtl"));
+        assert_eq!(detect_text_direction("42 — !"), None);
+    }
+
+    #[test]
+    fn test_content_text_renders_math_source() {
+        let html = r#"<p>Let <math alttext="{\displaystyle x^{2}}"><msup><mi>x</mi><mn>2</mn></msup></math> grow.</p>
+            <math display="block"><semantics><mi>y</mi><annotation encoding="application/x-tex">y</annotation></semantics></math>
+            <p><math><mi>z</mi><mo>+</mo><mn>1</mn></math></p>"#;
+
+        let text = content_text(html);
+        assert!(text.contains(r"Let \(x^{2}\) grow."));
+        assert!(text.contains(r"\[y\]"));
+        assert!(text.contains("z+1"));
+        assert_eq!(content_text("<p>No math</p>"), "No math");
+    }
+
+    #[test]
+    fn test_get_document_base_url() {
+        let page = "https://example.com/news/story.html";
+        let html = Html::parse_document(
+            r#"<html><head><base href="/static/"></head><body></body></html>"#,
+        );
+        assert_eq!(
+            get_document_base_url(&html, page),
+            "https://example.com/static/"
+        );
+
+        let html = Html::parse_document("<html><head></head><body></body></html>");
+        assert_eq!(get_document_base_url(&html, page), page);
+    }
+
     #[test]
     fn test_is_phrasing_content() {
         let html = Html::parse_fragment("<span>inline</span><div>block</div>");
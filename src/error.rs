@@ -100,6 +100,26 @@ pub enum ReadabilityError {
     #[error("Invalid URL: {0}")]
     InvalidUrl(String),
 
+    /// Invalid CSS selector provided.
+    ///
+    /// This error occurs when a selector in
+    /// [`ReadabilityOptions::content_selectors`](crate::ReadabilityOptions::content_selectors)
+    /// or [`ReadabilityOptions::strip_selectors`](crate::ReadabilityOptions::strip_selectors)
+    /// cannot be parsed. Selectors are validated when passed to
+    /// [`Readability::new`](crate::Readability::new).
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use readabilityrs::{Readability, ReadabilityError, ReadabilityOptions};
+    ///
+    /// let options = ReadabilityOptions::builder().content_selector("div[").build();
+    /// let result = Readability::new("<html></html>", None, Some(options));
+    /// assert!(matches!(result, Err(ReadabilityError::InvalidSelector(_))));
+    /// ```
+    #[error("Invalid CSS selector: {0}")]
+    InvalidSelector(String),
+
     /// Document structure is invalid or missing required elements.
     ///
     /// This error occurs when the document structure doesn't meet minimum requirements
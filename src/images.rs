@@ -0,0 +1,732 @@
+//! Lead image and site icon collection from metadata and article content.
+
+use crate::article::{ImageInfo, ImageSource, SiteIcon, SiteIconKind};
+use crate::options::SrcsetSelection;
+use crate::utils;
+use once_cell::sync::Lazy;
+use scraper::{ElementRef, Html, Selector};
+use serde_json::Value;
+
+impl ImageInfo {
+    pub(crate) fn new(url: &str, source: ImageSource) -> Option<Self> {
+        let url = url.trim();
+        if url.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            url: url.to_string(),
+            width: None,
+            height: None,
+            alt: None,
+            caption: None,
+            source,
+        })
+    }
+}
+
+/// Collect images from a Schema.org article item's `image` and `thumbnailUrl`
+///
+/// Accepts the same shapes as the single-image lookup: plain URLs, `ImageObject`s
+/// (`url`, `contentUrl` or an absolute `@id`) and arrays of either.
+pub fn extract_schema_images(item: &Value, source: ImageSource) -> Vec<ImageInfo> {
+    fn from_value(value: &Value, source: ImageSource, out: &mut Vec<ImageInfo>) {
+        match value {
+            Value::String(url) => out.extend(ImageInfo::new(url, source)),
+            Value::Array(values) => {
+                for value in values {
+                    from_value(value, source, out);
+                }
+            }
+            Value::Object(object) => {
+                let url = object
+                    .get("url")
+                    .or_else(|| object.get("contentUrl"))
+                    .and_then(|v| v.as_str())
+                    .or_else(|| {
+                        object
+                            .get("@id")
+                            .and_then(|v| v.as_str())
+                            .filter(|id| id.starts_with("http://") || id.starts_with("https://"))
+                    });
+
+                if let Some(mut image) = url.and_then(|url| ImageInfo::new(url, source)) {
+                    image.width = object.get("width").and_then(schema_dimension);
+                    image.height = object.get("height").and_then(schema_dimension);
+                    image.caption = object
+                        .get("caption")
+                        .and_then(|v| v.as_str())
+                        .and_then(non_empty_text);
+                    out.push(image);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut images = Vec::new();
+    if let Some(image) = item.get("image") {
+        from_value(image, source, &mut images);
+    }
+    if let Some(thumbnail) = item.get("thumbnailUrl") {
+        from_value(thumbnail, source, &mut images);
+    }
+    images
+}
+
+/// Collect Open Graph and Twitter Card images from meta tags
+///
+/// Follows the Open Graph structured property rules: `og:image` (or `og:image:url`)
+/// starts a new image and the `og:image:*` properties that follow describe it.
+/// `og:image:secure_url` replaces the URL of the image it describes.
+pub fn extract_meta_images(document: &Html) -> Vec<ImageInfo> {
+    static META_SELECTOR: Lazy<Selector> = Lazy::new(|| Selector::parse("meta").unwrap());
+
+    let mut open_graph: Vec<ImageInfo> = Vec::new();
+    let mut twitter: Vec<ImageInfo> = Vec::new();
+
+    for meta in document.select(&META_SELECTOR) {
+        let Some(key) = meta
+            .value()
+            .attr("property")
+            .or_else(|| meta.value().attr("name"))
+        else {
+            continue;
+        };
+        let Some(content) = meta.value().attr("content").map(str::trim) else {
+            continue;
+        };
+        if content.is_empty() {
+            continue;
+        }
+
+        match key.trim().to_lowercase().as_str() {
+            "og:image" | "og:image:url" => {
+                open_graph.extend(ImageInfo::new(content, ImageSource::OpenGraph))
+            }
+            "og:image:secure_url" => match open_graph.last_mut() {
+                Some(image) => image.url = content.to_string(),
+                None => open_graph.extend(ImageInfo::new(content, ImageSource::OpenGraph)),
+            },
+            "og:image:width" => set_last(&mut open_graph, |image| {
+                image.width = parse_dimension(content)
+            }),
+            "og:image:height" => set_last(&mut open_graph, |image| {
+                image.height = parse_dimension(content)
+            }),
+            "og:image:alt" => {
+                set_last(&mut open_graph, |image| image.alt = non_empty_text(content))
+            }
+            "twitter:image" | "twitter:image:src" => {
+                twitter.extend(ImageInfo::new(content, ImageSource::TwitterCard))
+            }
+            "twitter:image:alt" => {
+                set_last(&mut twitter, |image| image.alt = non_empty_text(content))
+            }
+            _ => {}
+        }
+    }
+
+    open_graph.extend(twitter);
+    open_graph
+}
+
+fn set_last(images: &mut [ImageInfo], apply: impl FnOnce(&mut ImageInfo)) {
+    if let Some(image) = images.last_mut() {
+        apply(image);
+    }
+}
+
+/// Collect `<img>` elements from extracted article content, in document order
+///
+/// Images without a usable `src` (missing, empty or a `data:` placeholder) are skipped.
+pub fn extract_content_images(html: &str) -> Vec<ImageInfo> {
+    static IMG_SELECTOR: Lazy<Selector> = Lazy::new(|| Selector::parse("img").unwrap());
+
+    let fragment = Html::parse_fragment(html);
+    fragment
+        .select(&IMG_SELECTOR)
+        .filter_map(|img| {
+            let src = img.value().attr("src")?;
+            if src.trim_start().starts_with("data:") {
+                return None;
+            }
+
+            let mut image = ImageInfo::new(src, ImageSource::Content)?;
+            image.width = img.value().attr("width").and_then(parse_dimension);
+            image.height = img.value().attr("height").and_then(parse_dimension);
+            image.alt = img.value().attr("alt").and_then(non_empty_text);
+            image.caption = figure_caption(img);
+            Some(image)
+        })
+        .collect()
+}
+
+/// Text of the `<figcaption>` belonging to the closest enclosing `<figure>`
+fn figure_caption(img: ElementRef) -> Option<String> {
+    static FIGCAPTION_SELECTOR: Lazy<Selector> =
+        Lazy::new(|| Selector::parse("figcaption").unwrap());
+
+    let figure = img
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .find(|ancestor| ancestor.value().name() == "figure")?;
+    let caption = figure.select(&FIGCAPTION_SELECTOR).next()?;
+    non_empty_text(&caption.text().collect::<String>())
+}
+
+/// Resolve image URLs against the base URL and merge duplicates
+///
+/// With a base URL, images whose URL does not resolve are dropped. The first
+/// occurrence of a URL keeps its position and source; later occurrences only
+/// fill in fields it is missing.
+pub fn finalize_images(images: Vec<ImageInfo>, base_url: Option<&str>) -> Vec<ImageInfo> {
+    let mut merged: Vec<ImageInfo> = Vec::with_capacity(images.len());
+
+    for mut image in images {
+        let url = utils::unescape_html_entities(&image.url);
+        image.url = match base_url {
+            Some(base) => match utils::resolve_url(&url, base) {
+                Some(resolved) => resolved,
+                None => continue,
+            },
+            None => url.trim().to_string(),
+        };
+
+        match merged.iter_mut().find(|existing| existing.url == image.url) {
+            Some(existing) => {
+                existing.width = existing.width.or(image.width);
+                existing.height = existing.height.or(image.height);
+                existing.alt = existing.alt.take().or(image.alt);
+                existing.caption = existing.caption.take().or(image.caption);
+            }
+            None => merged.push(image),
+        }
+    }
+
+    merged
+}
+
+/// Collect icons declared with `<link>` elements, in document order
+///
+/// Covers `icon` (and `shortcut icon`), `apple-touch-icon`,
+/// `apple-touch-icon-precomposed` and `mask-icon`.
+pub fn extract_site_icons(document: &Html) -> Vec<SiteIcon> {
+    static LINK_SELECTOR: Lazy<Selector> =
+        Lazy::new(|| Selector::parse("link[rel][href]").unwrap());
+
+    let mut icons: Vec<SiteIcon> = Vec::new();
+    for link in document.select(&LINK_SELECTOR) {
+        let rel = link.value().attr("rel").unwrap_or("").to_lowercase();
+        let kind = rel.split_whitespace().find_map(|token| match token {
+            "icon" => Some(SiteIconKind::Icon),
+            "apple-touch-icon" | "apple-touch-icon-precomposed" => {
+                Some(SiteIconKind::AppleTouchIcon)
+            }
+            "mask-icon" => Some(SiteIconKind::MaskIcon),
+            _ => None,
+        });
+        let Some(kind) = kind else {
+            continue;
+        };
+
+        let url = link.value().attr("href").unwrap_or("").trim();
+        if url.is_empty() || icons.iter().any(|icon| icon.url == url) {
+            continue;
+        }
+
+        let (width, height) = link
+            .value()
+            .attr("sizes")
+            .and_then(parse_largest_size)
+            .unzip();
+        icons.push(SiteIcon {
+            url: url.to_string(),
+            width,
+            height,
+            kind,
+        });
+    }
+    icons
+}
+
+/// Read the `publisher.logo` of a Schema.org article item
+pub fn extract_publisher_logo(item: &Value) -> Option<SiteIcon> {
+    let logo = item.get("publisher")?.get("logo")?;
+    let logo = logo
+        .as_array()
+        .and_then(|logos| logos.first())
+        .unwrap_or(logo);
+
+    let url = logo
+        .as_str()
+        .or_else(|| logo.get("url").and_then(|v| v.as_str()))
+        .or_else(|| logo.get("contentUrl").and_then(|v| v.as_str()))?
+        .trim();
+    if url.is_empty() {
+        return None;
+    }
+
+    Some(SiteIcon {
+        url: url.to_string(),
+        width: logo.get("width").and_then(schema_dimension),
+        height: logo.get("height").and_then(schema_dimension),
+        kind: SiteIconKind::PublisherLogo,
+    })
+}
+
+/// Pick the favicon: the first declared `icon`, else the default `/favicon.ico`
+pub fn pick_favicon(icons: &[SiteIcon]) -> Option<String> {
+    icons
+        .iter()
+        .find(|icon| icon.kind == SiteIconKind::Icon)
+        .or_else(|| {
+            icons
+                .iter()
+                .find(|icon| icon.kind == SiteIconKind::DefaultFavicon)
+        })
+        .map(|icon| icon.url.clone())
+}
+
+/// Parse an icon `sizes` attribute (e.g. `"16x16 32x32"`) into the largest size
+///
+/// `any` and malformed entries are ignored.
+fn parse_largest_size(sizes: &str) -> Option<(u32, u32)> {
+    sizes
+        .split_whitespace()
+        .filter_map(|size| {
+            let (width, height) = size
+                .to_lowercase()
+                .split_once('x')
+                .map(|(w, h)| (w.parse::<u32>().ok(), h.parse::<u32>().ok()))?;
+            Some((width?, height?))
+        })
+        .max_by_key(|(width, height)| u64::from(*width) * u64::from(*height))
+}
+
+/// Parse a pixel dimension such as `"1200"` or `"1200px"`
+fn parse_dimension(value: &str) -> Option<u32> {
+    let value = value.trim();
+    let value = value.strip_suffix("px").unwrap_or(value).trim();
+    value
+        .parse::<u32>()
+        .ok()
+        .or_else(|| {
+            value
+                .parse::<f64>()
+                .ok()
+                .filter(|v| v.is_finite() && *v >= 0.0)
+                .map(|v| v.round() as u32)
+        })
+        .filter(|v| *v > 0)
+}
+
+/// Read a Schema.org dimension: a number, a string, or a `QuantitativeValue`
+fn schema_dimension(value: &Value) -> Option<u32> {
+    match value {
+        Value::Number(n) => n.as_f64().and_then(|v| parse_dimension(&v.to_string())),
+        Value::String(s) => parse_dimension(s),
+        Value::Object(_) => value.get("value").and_then(schema_dimension),
+        _ => None,
+    }
+}
+
+fn non_empty_text(text: &str) -> Option<String> {
+    let text = utils::normalize_whitespace(text.trim());
+    if text.is_empty() {
+        None
+    } else {
+        Some(utils::unescape_html_entities(&text))
+    }
+}
+
+/// One image candidate of a `srcset` attribute
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SrcsetCandidate {
+    pub url: String,
+    /// Descriptor as written, such as `640w` or `2x`; empty when missing
+    pub descriptor: String,
+}
+
+impl SrcsetCandidate {
+    fn width(&self) -> Option<u32> {
+        let width = self.descriptor.strip_suffix(['w', 'W'])?;
+        width.parse().ok().filter(|width| *width > 0)
+    }
+
+    fn density(&self) -> Option<f32> {
+        if self.descriptor.is_empty() {
+            return Some(1.0);
+        }
+        let density = self.descriptor.strip_suffix(['x', 'X'])?;
+        density
+            .parse::<f32>()
+            .ok()
+            .filter(|d| d.is_finite() && *d > 0.0)
+    }
+}
+
+/// Parse a `srcset` attribute into its candidates
+///
+/// Follows the HTML parsing rules, so URLs containing commas (as used by image CDNs)
+/// are kept whole as long as they are followed by a descriptor or whitespace.
+pub(crate) fn parse_srcset(srcset: &str) -> Vec<SrcsetCandidate> {
+    let mut candidates = Vec::new();
+    let mut rest = srcset;
+
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == ',');
+        if rest.is_empty() {
+            break;
+        }
+
+        let url_end = rest
+            .find(|c: char| c.is_ascii_whitespace())
+            .unwrap_or(rest.len());
+        let (url, after) = rest.split_at(url_end);
+        let trimmed_url = url.trim_end_matches(',');
+        let descriptor = if trimmed_url.len() < url.len() {
+            // A trailing comma ends the candidate without descriptors
+            rest = after;
+            ""
+        } else {
+            let mut depth = 0usize;
+            let end = after
+                .char_indices()
+                .find(|(_, c)| match c {
+                    '(' => {
+                        depth += 1;
+                        false
+                    }
+                    ')' => {
+                        depth = depth.saturating_sub(1);
+                        false
+                    }
+                    ',' => depth == 0,
+                    _ => false,
+                })
+                .map_or(after.len(), |(i, _)| i);
+            rest = &after[end..];
+            after[..end].trim()
+        };
+
+        if !trimmed_url.is_empty() {
+            candidates.push(SrcsetCandidate {
+                url: trimmed_url.to_string(),
+                descriptor: descriptor.to_string(),
+            });
+        }
+    }
+
+    candidates
+}
+
+/// Serialize candidates back into a `srcset` attribute value
+pub(crate) fn serialize_srcset(candidates: &[SrcsetCandidate]) -> String {
+    candidates
+        .iter()
+        .map(|candidate| {
+            if candidate.descriptor.is_empty() {
+                candidate.url.clone()
+            } else {
+                format!("{} {}", candidate.url, candidate.descriptor)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Resolve every candidate URL of a `srcset` attribute against `base_url`
+///
+/// Candidates whose URL does not resolve are kept as written.
+pub(crate) fn resolve_srcset(srcset: &str, base_url: &str) -> String {
+    let candidates: Vec<SrcsetCandidate> = parse_srcset(srcset)
+        .into_iter()
+        .map(|mut candidate| {
+            if let Some(resolved) = utils::resolve_url(&candidate.url, base_url) {
+                candidate.url = resolved;
+            }
+            candidate
+        })
+        .collect();
+    serialize_srcset(&candidates)
+}
+
+/// Pick the candidate a browser would load for `selection`
+///
+/// With [`SrcsetSelection::Width`], width descriptors are compared against the slot
+/// width that `sizes` gives the image in a viewport that wide (the full viewport when
+/// `sizes` is missing). With [`SrcsetSelection::Density`], which has no viewport to
+/// compare against, width descriptors only count when no density descriptor is present,
+/// and the widest image wins. The smallest candidate at or above the target density is
+/// chosen, or the densest one when none reaches it.
+pub(crate) fn select_srcset_candidate<'a>(
+    candidates: &'a [SrcsetCandidate],
+    sizes: Option<&str>,
+    selection: SrcsetSelection,
+) -> Option<&'a SrcsetCandidate> {
+    let (target, slot) = match selection {
+        SrcsetSelection::Keep => return None,
+        SrcsetSelection::Width(viewport) => {
+            let slot = sizes.map_or(viewport as f32, |sizes| evaluate_sizes(sizes, viewport));
+            (1.0, Some(slot.max(1.0)))
+        }
+        SrcsetSelection::Density(density) => (density, None),
+    };
+
+    let densities: Vec<(f32, &SrcsetCandidate)> = candidates
+        .iter()
+        .filter_map(|candidate| {
+            let density = match candidate.width() {
+                Some(width) => width as f32 / slot?,
+                None => candidate.density()?,
+            };
+            Some((density, candidate))
+        })
+        .collect();
+    if densities.is_empty() {
+        return candidates
+            .iter()
+            .filter_map(|candidate| Some((candidate.width()?, candidate)))
+            .max_by_key(|(width, _)| *width)
+            .map(|(_, candidate)| candidate);
+    }
+
+    densities
+        .iter()
+        .filter(|(density, _)| *density >= target - 0.001)
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .or_else(|| densities.iter().max_by(|a, b| a.0.total_cmp(&b.0)))
+        .map(|(_, candidate)| *candidate)
+}
+
+/// Width in CSS pixels that a `sizes` attribute gives an image in a `viewport` wide window
+///
+/// Supports `min-width`/`max-width` media conditions and `px`, `vw`, `em` and `rem`
+/// lengths; entries using anything else are skipped. Falls back to the full viewport.
+fn evaluate_sizes(sizes: &str, viewport: u32) -> f32 {
+    for entry in sizes.split(',') {
+        let entry = entry.trim();
+        let (condition, length) = match entry.rsplit_once(|c: char| c.is_ascii_whitespace()) {
+            Some((condition, length)) => (condition.trim(), length),
+            None => ("", entry),
+        };
+        if !condition.is_empty() && !media_matches(condition, viewport) {
+            continue;
+        }
+        if let Some(width) = parse_css_length(length, viewport) {
+            return width;
+        }
+    }
+    viewport as f32
+}
+
+/// Whether a media query matches a screen `viewport` CSS pixels wide
+///
+/// Only media types and `min-width`/`max-width` features joined with `and` are
+/// understood; anything else does not match.
+pub(crate) fn media_matches(media: &str, viewport: u32) -> bool {
+    let media = media.trim().to_ascii_lowercase();
+    let media = media.strip_prefix("only ").unwrap_or(&media);
+    media.split(" and ").all(|part| {
+        let part = part.trim();
+        match part {
+            "" | "all" | "screen" => true,
+            _ => {
+                let Some(feature) = part.strip_prefix('(').and_then(|p| p.strip_suffix(')')) else {
+                    return false;
+                };
+                let Some((name, value)) = feature.split_once(':') else {
+                    return false;
+                };
+                let Some(value) = parse_css_length(value.trim(), viewport) else {
+                    return false;
+                };
+                match name.trim() {
+                    "min-width" => viewport as f32 >= value,
+                    "max-width" => viewport as f32 <= value,
+                    _ => false,
+                }
+            }
+        }
+    })
+}
+
+/// Parse a CSS length in `px`, `vw`, `em` or `rem` (at 16px) into CSS pixels
+fn parse_css_length(length: &str, viewport: u32) -> Option<f32> {
+    let length = length.trim().to_ascii_lowercase();
+    let (number, scale) = if let Some(number) = length.strip_suffix("px") {
+        (number, 1.0)
+    } else if let Some(number) = length.strip_suffix("vw") {
+        (number, viewport as f32 / 100.0)
+    } else if let Some(number) = length.strip_suffix("rem") {
+        (number, 16.0)
+    } else if let Some(number) = length.strip_suffix("em") {
+        (number, 16.0)
+    } else if length == "0" {
+        ("0", 0.0)
+    } else {
+        return None;
+    };
+    number
+        .parse::<f32>()
+        .ok()
+        .filter(|n| n.is_finite() && *n >= 0.0)
+        .map(|n| n * scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meta_images_with_dimensions() {
+        let html = r#"
+            <html><head>
+                <meta property="og:image" content="http://example.com/a.jpg">
+                <meta property="og:image:secure_url" content="https://example.com/a.jpg">
+                <meta property="og:image:width" content="1200">
+                <meta property="og:image:height" content="630">
+                <meta property="og:image:alt" content="A harbour at dawn">
+                <meta property="og:image" content="https://example.com/b.jpg">
+                <meta name="twitter:image" content="https://example.com/c.jpg">
+            </head></html>
+        "#;
+
+        let images = extract_meta_images(&Html::parse_document(html));
+        assert_eq!(images.len(), 3);
+        assert_eq!(images[0].url, "https://example.com/a.jpg");
+        assert_eq!(images[0].width, Some(1200));
+        assert_eq!(images[0].height, Some(630));
+        assert_eq!(images[0].alt.as_deref(), Some("A harbour at dawn"));
+        assert_eq!(images[1].width, None);
+        assert_eq!(images[2].source, ImageSource::TwitterCard);
+    }
+
+    #[test]
+    fn test_schema_image_objects() {
+        let item: Value = serde_json::json!({
+            "image": [
+                {"@type": "ImageObject", "url": "https://example.com/1.jpg", "width": 800, "height": "450", "caption": "Caption"},
+                "https://example.com/2.jpg"
+            ],
+            "thumbnailUrl": "https://example.com/thumb.jpg"
+        });
+
+        let images = extract_schema_images(&item, ImageSource::JsonLd);
+        let urls: Vec<_> = images.iter().map(|i| i.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            [
+                "https://example.com/1.jpg",
+                "https://example.com/2.jpg",
+                "https://example.com/thumb.jpg"
+            ]
+        );
+        assert_eq!(images[0].width, Some(800));
+        assert_eq!(images[0].height, Some(450));
+        assert_eq!(images[0].caption.as_deref(), Some("Caption"));
+    }
+
+    #[test]
+    fn test_site_icons() {
+        let html = r#"
+            <html><head>
+                <link rel="shortcut icon" href="/favicon.png">
+                <link rel="apple-touch-icon" sizes="120x120 180x180" href="/touch.png">
+                <link rel="mask-icon" href="/pinned.svg">
+                <link rel="stylesheet" href="/style.css">
+            </head></html>
+        "#;
+
+        let icons = extract_site_icons(&Html::parse_document(html));
+        assert_eq!(icons.len(), 3);
+        assert_eq!(icons[0].kind, SiteIconKind::Icon);
+        assert_eq!(icons[1].kind, SiteIconKind::AppleTouchIcon);
+        assert_eq!((icons[1].width, icons[1].height), (Some(180), Some(180)));
+        assert_eq!(icons[2].kind, SiteIconKind::MaskIcon);
+        assert_eq!(pick_favicon(&icons).as_deref(), Some("/favicon.png"));
+
+        let item = serde_json::json!({
+            "publisher": {"logo": {"@type": "ImageObject", "url": "/logo.png", "width": 600}}
+        });
+        let logo = extract_publisher_logo(&item).unwrap();
+        assert_eq!(logo.url, "/logo.png");
+        assert_eq!(logo.width, Some(600));
+    }
+
+    #[test]
+    fn test_srcset_parsing_and_selection() {
+        let candidates = parse_srcset(
+            "/img/w_320,h_240/a.jpg 320w, /img/w_640,h_480/a.jpg 640w,/img/w_1280/a.jpg 1280w",
+        );
+        assert_eq!(candidates.len(), 3);
+        assert_eq!(candidates[0].url, "/img/w_320,h_240/a.jpg");
+        assert_eq!(candidates[2].descriptor, "1280w");
+        assert_eq!(
+            resolve_srcset("a.jpg, b.jpg 2x", "https://example.com/news/story"),
+            "https://example.com/news/a.jpg, https://example.com/news/b.jpg 2x"
+        );
+
+        let pick = |sizes: Option<&str>, selection| {
+            select_srcset_candidate(&candidates, sizes, selection).map(|c| c.url.as_str())
+        };
+        assert_eq!(
+            pick(None, SrcsetSelection::Width(600)),
+            Some("/img/w_640,h_480/a.jpg")
+        );
+        assert_eq!(
+            pick(
+                Some("(max-width: 700px) 50vw, 900px"),
+                SrcsetSelection::Width(600)
+            ),
+            Some("/img/w_320,h_240/a.jpg")
+        );
+        assert_eq!(
+            pick(None, SrcsetSelection::Width(2000)),
+            Some("/img/w_1280/a.jpg")
+        );
+        assert_eq!(
+            pick(None, SrcsetSelection::Density(1.0)),
+            Some("/img/w_1280/a.jpg")
+        );
+        assert_eq!(pick(None, SrcsetSelection::Keep), None);
+
+        let densities = parse_srcset("a.jpg, a@2x.jpg 2x, a@3x.jpg 3x");
+        let chosen = select_srcset_candidate(&densities, None, SrcsetSelection::Density(1.5));
+        assert_eq!(chosen.map(|c| c.url.as_str()), Some("a@2x.jpg"));
+
+        assert!(media_matches(
+            "(min-width: 40em) and (max-width: 800px)",
+            700
+        ));
+        assert!(!media_matches("(min-width: 800px)", 700));
+        assert!(!media_matches("print", 700));
+    }
+
+    #[test]
+    fn test_content_images_and_merge() {
+        let html = r#"
+            <figure>
+                <img src="/photos/lead.jpg" width="1024" height="768px" alt="The lead photo">
+                <figcaption> Photo by   someone </figcaption>
+            </figure>
+            <p><img src="data:image/gif;base64,R0lGOD"></p>
+        "#;
+
+        let mut images = vec![ImageInfo::new(
+            "https://example.com/photos/lead.jpg",
+            ImageSource::OpenGraph,
+        )
+        .unwrap()];
+        images.extend(extract_content_images(html));
+
+        let images = finalize_images(images, Some("https://example.com/news/story"));
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].source, ImageSource::OpenGraph);
+        assert_eq!(images[0].width, Some(1024));
+        assert_eq!(images[0].height, Some(768));
+        assert_eq!(images[0].alt.as_deref(), Some("The lead photo"));
+        assert_eq!(images[0].caption.as_deref(), Some("Photo by someone"));
+    }
+}
@@ -0,0 +1,121 @@
+//! Statistical language identification of extracted article text.
+//!
+//! Detection is only available with the `lang-detect` cargo feature, which
+//! pulls in the offline [whatlang](https://docs.rs/whatlang) model. Without
+//! it, [`detect_language`] always returns `None`.
+
+use crate::article::DetectedLanguage;
+
+/// Identify the language of the article text
+///
+/// Returns `None` when the feature is disabled or the text is too short or
+/// ambiguous for the detector to pick a language.
+#[cfg(feature = "lang-detect")]
+pub fn detect_language(text: &str) -> Option<DetectedLanguage> {
+    let info = whatlang::detect(text.trim())?;
+    Some(DetectedLanguage {
+        code: iso_639_1(info.lang()).to_string(),
+        confidence: info.confidence(),
+        reliable: info.is_reliable(),
+    })
+}
+
+#[cfg(not(feature = "lang-detect"))]
+pub fn detect_language(_text: &str) -> Option<DetectedLanguage> {
+    None
+}
+
+/// Map a whatlang (ISO 639-3) language to its ISO 639-1 code, matching the
+/// two-letter codes pages use in `<html lang>`
+#[cfg(feature = "lang-detect")]
+fn iso_639_1(lang: whatlang::Lang) -> &'static str {
+    use whatlang::Lang;
+
+    match lang {
+        Lang::Afr => "af",
+        Lang::Aka => "ak",
+        Lang::Amh => "am",
+        Lang::Ara => "ar",
+        Lang::Aze => "az",
+        Lang::Bel => "be",
+        Lang::Ben => "bn",
+        Lang::Bul => "bg",
+        Lang::Cat => "ca",
+        Lang::Ces => "cs",
+        Lang::Cmn => "zh",
+        Lang::Dan => "da",
+        Lang::Deu => "de",
+        Lang::Ell => "el",
+        Lang::Eng => "en",
+        Lang::Epo => "eo",
+        Lang::Est => "et",
+        Lang::Fin => "fi",
+        Lang::Fra => "fr",
+        Lang::Guj => "gu",
+        Lang::Heb => "he",
+        Lang::Hin => "hi",
+        Lang::Hrv => "hr",
+        Lang::Hun => "hu",
+        Lang::Hye => "hy",
+        Lang::Ind => "id",
+        Lang::Ita => "it",
+        Lang::Jav => "jv",
+        Lang::Jpn => "ja",
+        Lang::Kan => "kn",
+        Lang::Kat => "ka",
+        Lang::Khm => "km",
+        Lang::Kor => "ko",
+        Lang::Lat => "la",
+        Lang::Lav => "lv",
+        Lang::Lit => "lt",
+        Lang::Mal => "ml",
+        Lang::Mar => "mr",
+        Lang::Mkd => "mk",
+        Lang::Mya => "my",
+        Lang::Nep => "ne",
+        Lang::Nld => "nl",
+        Lang::Nob => "nb",
+        Lang::Ori => "or",
+        Lang::Pan => "pa",
+        Lang::Pes => "fa",
+        Lang::Pol => "pl",
+        Lang::Por => "pt",
+        Lang::Ron => "ro",
+        Lang::Rus => "ru",
+        Lang::Sin => "si",
+        Lang::Slk => "sk",
+        Lang::Slv => "sl",
+        Lang::Sna => "sn",
+        Lang::Spa => "es",
+        Lang::Srp => "sr",
+        Lang::Swe => "sv",
+        Lang::Tam => "ta",
+        Lang::Tel => "te",
+        Lang::Tgl => "tl",
+        Lang::Tha => "th",
+        Lang::Tuk => "tk",
+        Lang::Tur => "tr",
+        Lang::Ukr => "uk",
+        Lang::Urd => "ur",
+        Lang::Uzb => "uz",
+        Lang::Vie => "vi",
+        Lang::Yid => "yi",
+        Lang::Zul => "zu",
+    }
+}
+
+#[cfg(all(test, feature = "lang-detect"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_language() {
+        let text = "La commission a adopté mardi le nouveau plan de transport, \
+                    après des années de débats entre les élus et les habitants de la ville.";
+        let detected = detect_language(text).unwrap();
+        assert_eq!(detected.code, "fr");
+        assert!(detected.confidence > 0.5);
+
+        assert!(detect_language("").is_none());
+    }
+}
@@ -112,13 +112,18 @@
 //! as possible while leveraging Rust's type system and safety guarantees.
 
 mod article;
+pub mod byline;
 mod cleaner;
 mod constants;
 mod content_extractor;
+mod discussion;
 mod dom_utils;
 mod error;
+mod images;
+mod language;
 mod metadata;
 mod options;
+mod pagination;
 mod post_processor;
 mod readability;
 mod readerable;
@@ -126,8 +131,15 @@ mod scoring;
 mod utils;
 
 // Public exports
-pub use article::Article;
+pub use article::{
+    Article, ArticleAccess, ArticleCandidate, ArticleMetadata, BylineConfidence, BylineSource,
+    CommentSystem, DetectedLanguage, Discussion, ExtractionFlags, ImageInfo, ImageSource,
+    Publisher, SiteIcon, SiteIconKind, TitleSource,
+};
 pub use error::{ReadabilityError, Result};
-pub use options::ReadabilityOptions;
+pub use options::{
+    ElementPolicy, MathNormalization, ReadabilityOptions, SemanticElementPolicy, SrcsetSelection,
+};
+pub use pagination::merge_pages;
 pub use readability::Readability;
 pub use readerable::{is_probably_readerable, ReaderableOptions};
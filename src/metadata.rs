@@ -1,7 +1,11 @@
 //! Metadata extraction from HTML documents (JSON-LD, meta tags, etc.).
 
+use crate::article::{
+    ArticleAccess, BylineConfidence, BylineSource, Discussion, ImageInfo, ImageSource, Publisher,
+    SiteIcon, SiteIconKind, TitleSource,
+};
 use crate::constants::REGEXPS;
-use crate::utils;
+use crate::{discussion, images, utils};
 use once_cell::sync::Lazy;
 use scraper::node::Node;
 use scraper::{ElementRef, Html, Selector};
@@ -13,12 +17,33 @@ use std::collections::HashMap;
 #[derive(Debug, Clone, Default)]
 pub struct Metadata {
     pub title: Option<String>,
+    pub title_source: Option<TitleSource>,
+    /// Every title source found, in priority order (the first one is `title`)
+    pub title_candidates: Vec<TitleCandidate>,
+    pub subtitle: Option<String>,
     pub byline: Option<String>,
+    pub byline_source: Option<BylineSource>,
+    pub byline_confidence: Option<BylineConfidence>,
     pub excerpt: Option<String>,
     pub site_name: Option<String>,
+    pub publisher: Option<Publisher>,
     pub published_time: Option<String>,
     pub lang: Option<String>,
     pub image: Option<String>,
+    pub access: Option<ArticleAccess>,
+    pub images: Vec<ImageInfo>,
+    pub canonical_url: Option<String>,
+    pub author_urls: Vec<String>,
+    pub site_icons: Vec<SiteIcon>,
+    pub discussion: Option<Discussion>,
+    pub next_page_url: Option<String>,
+}
+
+/// A possible article title and where it was found
+#[derive(Debug, Clone, PartialEq)]
+pub struct TitleCandidate {
+    pub text: String,
+    pub source: TitleSource,
 }
 
 /// Extract JSON-LD structured data from document
@@ -107,77 +132,209 @@ pub fn get_json_ld(document: &Html) -> Metadata {
                 continue;
             }
 
-            // Extract title (name or headline)
-            // Schema.org is flexible: "name" can be the article title OR publisher name
-            // Heuristic: if "name" matches publisher name, use "headline" instead
-            let name = parsed.get("name").and_then(|v| v.as_str());
-            let headline = parsed.get("headline").and_then(|v| v.as_str());
-            let publisher_name = parsed
-                .get("publisher")
-                .and_then(|p| p.get("name"))
-                .and_then(|n| n.as_str());
-
-            if metadata.title.is_none() {
-                if let (Some(name_str), Some(pub_name)) = (name, publisher_name) {
-                    if name_str.trim() == pub_name.trim() {
-                        if let Some(headline_str) = headline {
-                            metadata.title = Some(headline_str.trim().to_string());
-                        }
-                    } else {
-                        metadata.title = Some(name_str.trim().to_string());
-                    }
-                } else if let Some(name_str) = name {
-                    metadata.title = Some(name_str.trim().to_string());
-                } else if let Some(headline_str) = headline {
+            apply_schema_article(&mut metadata, &parsed);
+            metadata
+                .images
+                .extend(images::extract_schema_images(&parsed, ImageSource::JsonLd));
+        }
+    }
+
+    metadata
+}
+
+/// Fill any still-empty metadata fields from a Schema.org article item
+///
+/// Shared by the JSON-LD and microdata/RDFa readers, which both normalize their
+/// input into the same JSON shape before calling this.
+fn apply_schema_article(metadata: &mut Metadata, item: &Value) {
+    // Extract title (name or headline)
+    // Schema.org is flexible: "name" can be the article title OR publisher name
+    // Heuristic: if "name" matches publisher name, use "headline" instead
+    let name = item.get("name").and_then(|v| v.as_str());
+    let headline = item.get("headline").and_then(|v| v.as_str());
+    let publisher_name = item
+        .get("publisher")
+        .and_then(|p| p.get("name"))
+        .and_then(|n| n.as_str());
+
+    if metadata.title.is_none() {
+        if let (Some(name_str), Some(pub_name)) = (name, publisher_name) {
+            if name_str.trim() == pub_name.trim() {
+                if let Some(headline_str) = headline {
                     metadata.title = Some(headline_str.trim().to_string());
                 }
+            } else {
+                metadata.title = Some(name_str.trim().to_string());
             }
+        } else if let Some(name_str) = name {
+            metadata.title = Some(name_str.trim().to_string());
+        } else if let Some(headline_str) = headline {
+            metadata.title = Some(headline_str.trim().to_string());
+        }
+    }
 
-            if metadata.byline.is_none() {
-                if let Some(author) = parsed.get("author") {
-                    if let Some(author_name) = author.get("name").and_then(|v| v.as_str()) {
-                        metadata.byline = Some(author_name.trim().to_string());
-                    } else if let Some(authors) = author.as_array() {
-                        let names: Vec<String> = authors
-                            .iter()
-                            .filter_map(|a| a.get("name").and_then(|n| n.as_str()))
-                            .map(|n| n.trim().to_string())
-                            .collect();
-                        if !names.is_empty() {
-                            metadata.byline = Some(names.join(", "));
-                        }
-                    }
-                }
-            }
+    if metadata.subtitle.is_none() {
+        if let Some(subtitle) = item.get("alternativeHeadline").and_then(|v| v.as_str()) {
+            metadata.subtitle = Some(subtitle.trim().to_string());
+        }
+    }
 
-            if metadata.excerpt.is_none() {
-                if let Some(description) = parsed.get("description").and_then(|v| v.as_str()) {
-                    metadata.excerpt = Some(description.trim().to_string());
+    if metadata.byline.is_none() {
+        if let Some(author) = item.get("author") {
+            if let Some(author_name) = author.get("name").and_then(|v| v.as_str()) {
+                metadata.byline = Some(author_name.trim().to_string());
+            } else if let Some(authors) = author.as_array() {
+                let names: Vec<String> = authors
+                    .iter()
+                    .filter_map(|a| a.get("name").and_then(|n| n.as_str()))
+                    .map(|n| n.trim().to_string())
+                    .collect();
+                if !names.is_empty() {
+                    metadata.byline = Some(names.join(", "));
                 }
             }
+        }
+    }
 
-            if metadata.site_name.is_none() {
-                if let Some(publisher) = parsed.get("publisher") {
-                    if let Some(pub_name) = publisher.get("name").and_then(|v| v.as_str()) {
-                        metadata.site_name = Some(pub_name.trim().to_string());
-                    }
-                }
-            }
+    if metadata.author_urls.is_empty() {
+        if let Some(author) = item.get("author") {
+            let authors = match author.as_array() {
+                Some(authors) => authors.iter().collect(),
+                None => vec![author],
+            };
+            metadata.author_urls = authors
+                .into_iter()
+                .filter_map(|a| a.get("url").and_then(|u| u.as_str()))
+                .map(|u| u.trim().to_string())
+                .filter(|u| !u.is_empty())
+                .collect();
+        }
+    }
 
-            if metadata.published_time.is_none() {
-                if let Some(date_published) = parsed.get("datePublished").and_then(|v| v.as_str()) {
-                    metadata.published_time = Some(date_published.trim().to_string());
-                }
-            }
+    if metadata.excerpt.is_none() {
+        if let Some(description) = item.get("description").and_then(|v| v.as_str()) {
+            metadata.excerpt = Some(description.trim().to_string());
+        }
+    }
 
-            // Extract image from JSON-LD
-            if metadata.image.is_none() {
-                metadata.image = extract_json_ld_image(&parsed);
+    if metadata.site_name.is_none() {
+        if let Some(publisher) = item.get("publisher") {
+            if let Some(pub_name) = publisher.get("name").and_then(|v| v.as_str()) {
+                metadata.site_name = Some(pub_name.trim().to_string());
             }
         }
     }
 
-    metadata
+    if metadata.publisher.is_none() {
+        metadata.publisher = extract_schema_publisher(item);
+    }
+
+    if metadata.published_time.is_none() {
+        if let Some(date_published) = item.get("datePublished").and_then(|v| v.as_str()) {
+            metadata.published_time = Some(date_published.trim().to_string());
+        }
+    }
+
+    // Extract image
+    if metadata.image.is_none() {
+        metadata.image = extract_json_ld_image(item);
+    }
+
+    if metadata.access.is_none() {
+        metadata.access = extract_schema_access(item);
+    }
+
+    if metadata.site_icons.is_empty() {
+        metadata
+            .site_icons
+            .extend(images::extract_publisher_logo(item));
+    }
+
+    if metadata.discussion.is_none() {
+        metadata.discussion = discussion::extract_schema_discussion(item);
+    }
+}
+
+/// Read the Schema.org `publisher` organization: name, home page and logo
+fn extract_schema_publisher(item: &Value) -> Option<Publisher> {
+    let publisher = item.get("publisher")?;
+    let publisher = publisher
+        .as_array()
+        .and_then(|publishers| publishers.first())
+        .unwrap_or(publisher);
+
+    let text = |key: &str| {
+        publisher
+            .get(key)
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+    };
+    let publisher = Publisher {
+        name: text("name"),
+        url: text("url").or_else(|| text("@id").filter(|id| utils::is_url(id))),
+        logo: images::extract_publisher_logo(item).map(|logo| logo.url),
+        ..Default::default()
+    };
+    (publisher != Publisher::default()).then_some(publisher)
+}
+
+/// Derive access restrictions from Schema.org paywall markup
+///
+/// Follows the structured data publishers use to mark paywalled content: a
+/// top-level `isAccessibleForFree` flag, and/or `hasPart` sections with a
+/// `cssSelector` whose `isAccessibleForFree` is false.
+fn extract_schema_access(item: &Value) -> Option<ArticleAccess> {
+    let has_locked_part = match item.get("hasPart") {
+        Some(Value::Array(parts)) => parts.iter().any(is_locked_schema_part),
+        Some(part) => is_locked_schema_part(part),
+        None => false,
+    };
+    if has_locked_part {
+        return Some(ArticleAccess::SubscriberOnly);
+    }
+
+    match item.get("isAccessibleForFree").and_then(schema_boolean) {
+        Some(true) => Some(ArticleAccess::Free),
+        Some(false) => Some(ArticleAccess::SubscriberOnly),
+        None => None,
+    }
+}
+
+fn is_locked_schema_part(part: &Value) -> bool {
+    let has_selector = part
+        .get("cssSelector")
+        .and_then(|v| v.as_str())
+        .is_some_and(|selector| !selector.trim().is_empty());
+
+    has_selector && part.get("isAccessibleForFree").and_then(schema_boolean) == Some(false)
+}
+
+/// Read a Schema.org boolean, which publishers emit as JSON booleans or as
+/// `"True"`/`"False"` strings (optionally as `https://schema.org/True`).
+fn schema_boolean(value: &Value) -> Option<bool> {
+    match value {
+        Value::Bool(b) => Some(*b),
+        Value::String(s) => match strip_schema_prefix(s.trim()).to_ascii_lowercase().as_str() {
+            "true" => Some(true),
+            "false" => Some(false),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Map an `article:content_tier` meta value to an access level
+fn parse_content_tier(tier: &str) -> Option<ArticleAccess> {
+    match tier.trim().to_ascii_lowercase().as_str() {
+        "free" => Some(ArticleAccess::Free),
+        "metered" => Some(ArticleAccess::Metered),
+        "locked" | "premium" | "subscriber" | "subscription" | "paid" => {
+            Some(ArticleAccess::SubscriberOnly)
+        }
+        _ => None,
+    }
 }
 
 /// Extract image URL from JSON-LD data
@@ -244,13 +401,309 @@ fn extract_json_ld_image(parsed: &Value) -> Option<String> {
     None
 }
 
+/// Attribute names that describe one of the inline Schema.org syntaxes.
+struct InlineItemSyntax {
+    /// Attribute that opens a new item scope (`itemscope` / `typeof`)
+    scope_attr: &'static str,
+    /// Attribute carrying the item type (`itemtype` / `typeof`)
+    type_attr: &'static str,
+    /// Attribute naming a property of the enclosing item (`itemprop` / `property`)
+    prop_attr: &'static str,
+}
+
+const MICRODATA: InlineItemSyntax = InlineItemSyntax {
+    scope_attr: "itemscope",
+    type_attr: "itemtype",
+    prop_attr: "itemprop",
+};
+
+const RDFA: InlineItemSyntax = InlineItemSyntax {
+    scope_attr: "typeof",
+    type_attr: "typeof",
+    prop_attr: "property",
+};
+
+/// Extract article metadata from Schema.org microdata and RDFa markup
+///
+/// Looks for `itemscope`/`itemtype` (microdata) and `typeof`/`property` (RDFa)
+/// items whose type is one of the Schema.org article types, converts each into
+/// the same JSON shape as a JSON-LD object and reads it with the JSON-LD rules.
+/// Nested items such as an `author` Person or `publisher` Organization become
+/// nested objects, so `author.name` and `publisher.name` resolve as usual.
+///
+/// A few differences from JSON-LD keep page chrome out of the result: `author`
+/// properties that only appear in a footer of the item (blog templates put a
+/// profile card there) are ignored, repeated authors are listed once, agency
+/// credits such as "AFP" are dropped like in the DOM byline path, and the
+/// publisher's `name` never becomes `site_name`, since microdata publishers are
+/// usually the legal entity from the copyright line. The publisher is still
+/// reported in [`Metadata::publisher`].
+pub fn get_microdata(document: &Html) -> Metadata {
+    static MICRODATA_SELECTOR: Lazy<Selector> =
+        Lazy::new(|| Selector::parse("[itemscope][itemtype]").unwrap());
+    static RDFA_SELECTOR: Lazy<Selector> = Lazy::new(|| Selector::parse("[typeof]").unwrap());
+
+    let mut metadata = Metadata::default();
+
+    for (selector, syntax) in [(&*MICRODATA_SELECTOR, &MICRODATA), (&*RDFA_SELECTOR, &RDFA)] {
+        for element in document.select(selector) {
+            let Some(item_type) = schema_article_type(&element, syntax) else {
+                continue;
+            };
+            let mut item = inline_item_to_value(element, syntax);
+            item["@type"] = Value::String(item_type);
+            if authors_only_in_footer(element, syntax) {
+                if let Some(properties) = item.as_object_mut() {
+                    properties.remove("author");
+                    properties.remove("creator");
+                }
+            }
+            dedupe_authors(&mut item);
+            let site_name = metadata.site_name.take();
+            let had_byline = metadata.byline.is_some();
+            apply_schema_article(&mut metadata, &item);
+            metadata.site_name = site_name;
+            if !had_byline {
+                metadata.byline = metadata.byline.take().and_then(|byline| {
+                    match utils::clean_byline_text_with_reason(&byline) {
+                        utils::CleanBylineOutcome::Accepted(cleaned) => Some(cleaned),
+                        utils::CleanBylineOutcome::DroppedOrgCredit => None,
+                        utils::CleanBylineOutcome::Dropped => Some(byline),
+                    }
+                });
+            }
+            metadata
+                .images
+                .extend(images::extract_schema_images(&item, ImageSource::Microdata));
+        }
+    }
+
+    metadata
+}
+
+/// Drop repeated `author` entries, as templates often mark the same byline up twice
+fn dedupe_authors(item: &mut Value) {
+    let Some(Value::Array(authors)) = item.get_mut("author") else {
+        return;
+    };
+    let mut seen = std::collections::HashSet::new();
+    authors.retain(|author| {
+        author
+            .get("name")
+            .and_then(Value::as_str)
+            .or(author.as_str())
+            .map(|name| name.trim().to_lowercase())
+            .is_none_or(|name| seen.insert(name))
+    });
+}
+
+/// Whether every `author`/`creator` property of an item sits inside a footer.
+///
+/// Properties of nested items are not the item's own and are skipped.
+fn authors_only_in_footer(scope: ElementRef, syntax: &InlineItemSyntax) -> bool {
+    let is_footer = |element: &ElementRef| {
+        let value = element.value();
+        let class_and_id = format!(
+            "{} {}",
+            value.attr("class").unwrap_or_default(),
+            value.attr("id").unwrap_or_default()
+        )
+        .to_lowercase();
+        value.name() == "footer" || class_and_id.contains("footer")
+    };
+
+    let mut found = false;
+    for element in scope.descendants().filter_map(ElementRef::wrap).skip(1) {
+        let Some(props) = element.value().attr(syntax.prop_attr) else {
+            continue;
+        };
+        let is_author = props
+            .split_whitespace()
+            .map(strip_schema_prefix)
+            .any(|prop| matches!(prop, "author" | "creator"));
+        if !is_author {
+            continue;
+        }
+
+        let ancestors: Vec<ElementRef> = element
+            .ancestors()
+            .filter_map(ElementRef::wrap)
+            .take_while(|ancestor| ancestor.id() != scope.id())
+            .collect();
+        if ancestors
+            .iter()
+            .any(|ancestor| ancestor.value().attr(syntax.scope_attr).is_some())
+        {
+            continue;
+        }
+        if !ancestors.iter().any(is_footer) {
+            return false;
+        }
+        found = true;
+    }
+    found
+}
+
+/// Return the Schema.org article type of an inline item, if it has one.
+fn schema_article_type(element: &ElementRef, syntax: &InlineItemSyntax) -> Option<String> {
+    let types = element.value().attr(syntax.type_attr)?;
+    let uses_schema_vocab = ancestor_vocab_is_schema(element);
+
+    types.split_whitespace().find_map(|token| {
+        let is_schema = token.contains("schema.org")
+            || token.starts_with("schema:")
+            || (uses_schema_vocab && !token.contains(':'));
+        if !is_schema {
+            return None;
+        }
+        let name = strip_schema_prefix(token);
+        REGEXPS
+            .json_ld_article_types
+            .is_match(name)
+            .then(|| name.to_string())
+    })
+}
+
+/// Check whether an RDFa `vocab` on the element or an ancestor points at Schema.org.
+fn ancestor_vocab_is_schema(element: &ElementRef) -> bool {
+    let mut current = Some(*element);
+    while let Some(el) = current {
+        if let Some(vocab) = el.value().attr("vocab") {
+            return vocab.contains("schema.org");
+        }
+        current = el.parent().and_then(ElementRef::wrap);
+    }
+    false
+}
+
+/// Reduce `https://schema.org/headline` or `schema:headline` to `headline`.
+fn strip_schema_prefix(token: &str) -> &str {
+    let token = token.trim_end_matches('/');
+    token
+        .rsplit(['/', ':', '#'])
+        .next()
+        .filter(|name| !name.is_empty())
+        .unwrap_or(token)
+}
+
+/// Convert an inline item scope into a JSON object keyed by property name.
+///
+/// Properties that repeat become arrays. Nested item scopes are converted
+/// recursively and are not descended into for the outer item's properties.
+fn inline_item_to_value(scope: ElementRef, syntax: &InlineItemSyntax) -> Value {
+    fn collect(
+        element: ElementRef,
+        syntax: &InlineItemSyntax,
+        out: &mut serde_json::Map<String, Value>,
+    ) {
+        for child in element.children().filter_map(ElementRef::wrap) {
+            let opens_scope = child.value().attr(syntax.scope_attr).is_some();
+
+            if let Some(props) = child.value().attr(syntax.prop_attr) {
+                for prop in props.split_whitespace().map(strip_schema_prefix) {
+                    let value = if opens_scope {
+                        inline_item_to_value(child, syntax)
+                    } else {
+                        inline_property_value(child, prop)
+                    };
+
+                    match out.get_mut(prop) {
+                        Some(Value::Array(existing)) => existing.push(value),
+                        Some(existing) => {
+                            let first = existing.take();
+                            *existing = Value::Array(vec![first, value]);
+                        }
+                        None => {
+                            out.insert(prop.to_string(), value);
+                        }
+                    }
+                }
+            }
+
+            if !opens_scope {
+                collect(child, syntax, out);
+            }
+        }
+    }
+
+    let mut properties = serde_json::Map::new();
+    collect(scope, syntax, &mut properties);
+    Value::Object(properties)
+}
+
+/// Read a property value following the microdata value rules.
+///
+/// `content` always wins. URL-valued properties then read `src`/`href`/`data`
+/// by tag, everything else reads `datetime`/`value` or the element text.
+/// Plain-text `author` and `publisher` values are wrapped as `{"name": ...}`
+/// so they look like the Person/Organization objects JSON-LD would contain.
+fn inline_property_value(element: ElementRef, prop: &str) -> Value {
+    const URL_PROPERTIES: [&str; 8] = [
+        "url",
+        "image",
+        "thumbnailUrl",
+        "logo",
+        "sameAs",
+        "contentUrl",
+        "embedUrl",
+        "mainEntityOfPage",
+    ];
+
+    let value = element.value();
+    let url_attr = match value.name() {
+        "audio" | "embed" | "iframe" | "img" | "source" | "track" | "video" => Some("src"),
+        "a" | "area" | "link" => Some("href"),
+        "object" => Some("data"),
+        _ => None,
+    };
+    let value_attr = match value.name() {
+        "data" | "meter" => Some("value"),
+        "time" => Some("datetime"),
+        _ => None,
+    };
+
+    let text = value
+        .attr("content")
+        .map(str::to_string)
+        .or_else(|| {
+            URL_PROPERTIES
+                .contains(&prop)
+                .then(|| url_attr.and_then(|name| value.attr(name)))
+                .flatten()
+                .map(str::to_string)
+        })
+        .or_else(|| {
+            value_attr
+                .and_then(|name| value.attr(name))
+                .map(str::to_string)
+        })
+        .unwrap_or_else(|| utils::normalize_whitespace(element.text().collect::<String>().trim()));
+    let text = text.trim().to_string();
+
+    if matches!(prop, "author" | "creator" | "publisher") {
+        serde_json::json!({ "name": text })
+    } else {
+        Value::String(text)
+    }
+}
+
 /// Extract article metadata from meta tags
 ///
 /// Supports OpenGraph, Twitter Cards, Dublin Core, and standard meta tags.
+///
+/// Each field is taken from the first source that provides it, in this order:
+/// 1. JSON-LD (`json_ld`, see [`get_json_ld`])
+/// 2. Meta tags
+/// 3. Schema.org microdata and RDFa items (see [`get_microdata`])
+/// 4. Document heuristics (`link[rel=image_src]`, `itemprop=image`)
+///
+/// Title and byline are the exception: the `<title>` element and the DOM byline
+/// heuristics already run before microdata/RDFa, which only fills those two
+/// fields when the document heuristics find nothing.
 pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
     let mut values: HashMap<String, String> = HashMap::new();
     let property_pattern = regex::Regex::new(
-        r"(?i)\s*(article|dc|dcterm|og|twitter)\s*:\s*(author|creator|description|published_time|title|site_name|image:url|image:secure_url|image$)\s*"
+        r"(?i)\s*(article|dc|dcterm|og|twitter)\s*:\s*(author|content_tier|creator|description|published_time|title|site_name|image:url|image:secure_url|image$)\s*"
     ).unwrap();
 
     let name_pattern = regex::Regex::new(
@@ -297,59 +750,107 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
         }
     }
 
+    let microdata = get_microdata(document);
+
+    let meta_title = values
+        .get("dc:title")
+        .or_else(|| values.get("dcterm:title"))
+        .or_else(|| values.get("og:title"))
+        .or_else(|| values.get("weibo:article:title"))
+        .or_else(|| values.get("weibo:webpage:title"))
+        .or_else(|| values.get("title"))
+        .or_else(|| values.get("twitter:title"))
+        .or_else(|| values.get("parsely-title"))
+        .cloned();
+
+    // Title sources in priority order; microdata only counts without a `<title>`.
+    // The raw `<title>` segments come last, only as alternatives for `select_title`.
+    let mut title_candidates: Vec<TitleCandidate> = [
+        (json_ld.title, TitleSource::JsonLd),
+        (meta_title, TitleSource::Meta),
+        (extract_title_from_document(document), TitleSource::Document),
+        (microdata.title, TitleSource::Microdata),
+    ]
+    .into_iter()
+    .filter_map(|(text, source)| text.map(|text| TitleCandidate { text, source }))
+    .collect();
+    title_candidates.extend(document_title_segments(document).into_iter().map(|text| {
+        TitleCandidate {
+            text,
+            source: TitleSource::Document,
+        }
+    }));
+    for candidate in &mut title_candidates {
+        candidate.text = utils::unescape_html_entities(candidate.text.trim());
+    }
+    title_candidates.retain(|candidate| !candidate.text.is_empty());
+
     let mut metadata = Metadata {
-        title: json_ld.title.or_else(|| {
-            values
-                .get("dc:title")
-                .or_else(|| values.get("dcterm:title"))
-                .or_else(|| values.get("og:title"))
-                .or_else(|| values.get("weibo:article:title"))
-                .or_else(|| values.get("weibo:webpage:title"))
-                .or_else(|| values.get("title"))
-                .or_else(|| values.get("twitter:title"))
-                .or_else(|| values.get("parsely-title"))
-                .cloned()
-        }),
+        title: title_candidates.first().map(|c| c.text.clone()),
+        title_source: title_candidates.first().map(|c| c.source),
         ..Default::default()
     };
-
-    if metadata.title.is_none() {
-        metadata.title = extract_title_from_document(document);
-    }
+    metadata.title_candidates = title_candidates;
 
     if metadata.title.is_none() {
         metadata.title = Some(String::new());
     }
 
+    // The dek is only a subtitle when it says something the title doesn't
+    metadata.subtitle = json_ld
+        .subtitle
+        .or(microdata.subtitle)
+        .or_else(|| extract_subtitle_from_document(document))
+        .map(|s| utils::normalize_whitespace(utils::unescape_html_entities(&s).trim()))
+        .filter(|s| !s.is_empty())
+        .filter(|s| {
+            metadata
+                .title
+                .as_deref()
+                .is_none_or(|title| !s.eq_ignore_ascii_case(title.trim()))
+        });
+
     let article_author = values
         .get("article:author")
         .or_else(|| values.get("article:author_name"))
         .filter(|v| !utils::is_url(v))
         .cloned();
 
-    let dom_byline = extract_byline_from_document(document);
-    let mut meta_byline = json_ld.byline.or_else(|| {
-        values
-            .get("dc:creator")
-            .or_else(|| values.get("dcterm:creator"))
-            .or_else(|| values.get("author"))
-            .or_else(|| values.get("parsely-author"))
-            .or(article_author.as_ref())
-            .cloned()
-    });
+    metadata.lang = extract_language_from_document(document);
+    let dom_byline = extract_byline_from_document(document, metadata.lang.as_deref());
+    let meta_author = values
+        .get("dc:creator")
+        .or_else(|| values.get("dcterm:creator"))
+        .or_else(|| values.get("author"))
+        .or_else(|| values.get("parsely-author"))
+        .or(article_author.as_ref())
+        .cloned();
+
+    // Where the byline came from and how much to trust it, updated with every override
+    let (mut meta_byline, mut byline_origin) = match json_ld.byline {
+        Some(byline) => (Some(byline), (BylineSource::JsonLd, BylineConfidence::High)),
+        None => (meta_author, (BylineSource::Meta, BylineConfidence::Medium)),
+    };
 
     if let Some(dom_value) = dom_byline.clone() {
-        let dom_text = dom_value.text.clone();
-        match &meta_byline {
+        let prefer_dom = match &meta_byline {
             Some(existing) => {
-                if should_prefer_dom_byline(existing, &dom_text, dom_value.confidence) {
-                    meta_byline = Some(dom_text);
-                }
+                should_prefer_dom_byline(existing, &dom_value.text, dom_value.confidence)
             }
-            None => meta_byline = Some(dom_text),
+            None => true,
+        };
+        if prefer_dom {
+            meta_byline = Some(dom_value.text);
+            byline_origin = (dom_value.source, dom_value.confidence);
         }
     }
 
+    if meta_byline.is_none() {
+        if let Some(byline) = microdata.byline {
+            meta_byline = Some(byline);
+            byline_origin = (BylineSource::Microdata, BylineConfidence::Medium);
+        }
+    }
     metadata.byline = meta_byline;
 
     metadata.excerpt = json_ld.excerpt.or_else(|| {
@@ -362,6 +863,7 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
             .or_else(|| values.get("description"))
             .or_else(|| values.get("twitter:description"))
             .cloned()
+            .or(microdata.excerpt)
     });
 
     metadata.site_name = json_ld
@@ -373,6 +875,7 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
             .get("article:published_time")
             .or_else(|| values.get("parsely-pub-date"))
             .cloned()
+            .or(microdata.published_time)
     });
 
     // Extract image from meta tags with priority order
@@ -385,16 +888,72 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
             .or_else(|| values.get("thumbnail"))
             .or_else(|| values.get("image"))
             .cloned()
+            .or(microdata.image)
     });
 
+    // A publisher's JSON-LD only says whether an article is free; the content tier
+    // meta tag can refine a restricted article to a metered one.
+    let content_tier = values
+        .get("article:content_tier")
+        .and_then(|tier| parse_content_tier(tier));
+    metadata.access = match (json_ld.access, content_tier) {
+        (Some(ArticleAccess::SubscriberOnly), Some(ArticleAccess::Metered)) => {
+            Some(ArticleAccess::Metered)
+        }
+        (json_ld_access, content_tier) => json_ld_access.or(content_tier).or(microdata.access),
+    };
+
+    metadata.canonical_url = extract_canonical_url(document);
+
+    metadata.author_urls = if !json_ld.author_urls.is_empty() {
+        json_ld.author_urls
+    } else if let Some(author_url) = values.get("article:author").filter(|v| utils::is_url(v)) {
+        vec![author_url.clone()]
+    } else if !microdata.author_urls.is_empty() {
+        microdata.author_urls
+    } else {
+        extract_author_urls_from_document(document)
+    };
+
+    metadata.site_icons = images::extract_site_icons(document);
+    metadata.site_icons.extend(
+        json_ld
+            .site_icons
+            .into_iter()
+            .chain(microdata.site_icons)
+            .take(1),
+    );
+
+    metadata.publisher = build_publisher(
+        document,
+        json_ld.publisher.or(microdata.publisher),
+        [metadata.site_name.as_ref(), values.get("og:site_name")],
+    );
+
+    metadata.discussion = discussion::merge_discussion(
+        json_ld.discussion.or(microdata.discussion),
+        discussion::extract_document_discussion(document),
+    );
+
+    let document_image = extract_image_from_document(document);
+
     // If no image found in standard meta tags, try additional sources
     if metadata.image.is_none() {
-        metadata.image = extract_image_from_document(document);
+        metadata.image = document_image.clone();
     }
 
-    metadata.lang = extract_language_from_document(document);
+    // Every candidate image, in the same priority order used for `image`
+    metadata.images = json_ld.images;
+    metadata
+        .images
+        .extend(images::extract_meta_images(document));
+    metadata.images.extend(microdata.images);
+    metadata.images.extend(
+        document_image
+            .as_deref()
+            .and_then(|url| ImageInfo::new(url, ImageSource::Document)),
+    );
 
-    metadata.title = metadata.title.map(|t| utils::unescape_html_entities(&t));
     metadata.byline = metadata
         .byline
         .map(|b| utils::unescape_html_entities(&b))
@@ -420,6 +979,7 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
         if should_prefer_dom_byline(&existing, &dom_value.text, dom_value.confidence) {
             metadata.byline =
                 utils::clean_byline_text(&dom_value.text).or_else(|| Some(dom_value.text.clone()));
+            byline_origin = (dom_value.source, dom_value.confidence);
         }
     }
 
@@ -437,18 +997,39 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
             Some(existing) => {
                 if should_prefer_caps_standfirst(existing, &caps_candidate) {
                     metadata.byline = Some(caps_candidate);
+                    byline_origin = (BylineSource::Standfirst, BylineConfidence::High);
                 }
             }
-            None => metadata.byline = Some(caps_candidate),
+            None => {
+                metadata.byline = Some(caps_candidate);
+                byline_origin = (BylineSource::Standfirst, BylineConfidence::High);
+            }
         }
     }
 
-    if let (Some(byline), Some(site_name)) = (metadata.byline.clone(), metadata.site_name.clone()) {
-        if utils::is_byline_redundant_with_site_name(&byline, &site_name) {
+    // Prefixes like "Von" and date clauses in the page language
+    metadata.byline = metadata
+        .byline
+        .and_then(|b| utils::clean_localized_byline(&b, metadata.lang.as_deref()));
+
+    // Only the site name proper counts here: publisher aliases such as an app
+    // title ("Recipes by Jane Smith") often contain the author's real name
+    if let Some(byline) = &metadata.byline {
+        let site_names = [metadata.site_name.as_ref(), values.get("og:site_name")];
+        if site_names
+            .into_iter()
+            .flatten()
+            .any(|name| utils::is_byline_redundant_with_site_name(byline, name))
+        {
             metadata.byline = None;
         }
     }
 
+    if metadata.byline.is_some() {
+        metadata.byline_source = Some(byline_origin.0);
+        metadata.byline_confidence = Some(byline_origin.1);
+    }
+
     metadata.published_time = metadata
         .published_time
         .map(|p| utils::unescape_html_entities(&p));
@@ -465,6 +1046,285 @@ pub fn get_article_metadata(document: &Html, json_ld: Metadata) -> Metadata {
     metadata
 }
 
+/// Resolve URL-valued metadata fields against the document base URL
+///
+/// `base_url` should already account for `<base href>` (see
+/// [`crate::dom_utils::get_document_base_url`]). Protocol- and path-relative
+/// values become absolute; values that still don't parse as URLs are dropped.
+/// The default `/favicon.ico` is resolved against `page_url` instead, since
+/// browsers request it from the page's own origin whatever `<base>` says.
+pub fn resolve_metadata_urls(metadata: &mut Metadata, base_url: &str, page_url: &str) {
+    let resolve = |url: &str| utils::resolve_url(&utils::unescape_html_entities(url), base_url);
+
+    metadata.image = metadata.image.as_deref().and_then(resolve);
+    metadata.canonical_url = metadata.canonical_url.as_deref().and_then(resolve);
+
+    if let Some(discussion) = &mut metadata.discussion {
+        discussion.url = discussion.url.as_deref().and_then(resolve);
+    }
+
+    if let Some(publisher) = &mut metadata.publisher {
+        publisher.url = publisher.url.as_deref().and_then(resolve);
+        publisher.logo = publisher.logo.as_deref().and_then(resolve);
+    }
+
+    let mut author_urls: Vec<String> = Vec::new();
+    for url in metadata.author_urls.iter().filter_map(|url| resolve(url)) {
+        if !author_urls.contains(&url) {
+            author_urls.push(url);
+        }
+    }
+    metadata.author_urls = author_urls;
+
+    let mut site_icons: Vec<SiteIcon> = Vec::new();
+    for mut icon in std::mem::take(&mut metadata.site_icons) {
+        let Some(url) = resolve(&icon.url) else {
+            continue;
+        };
+        if !site_icons.iter().any(|existing| existing.url == url) {
+            icon.url = url;
+            site_icons.push(icon);
+        }
+    }
+
+    // Browsers request /favicon.ico when a page declares no icon
+    if let Some(url) = utils::resolve_url("/favicon.ico", page_url) {
+        if !site_icons.iter().any(|existing| existing.url == url) {
+            site_icons.push(SiteIcon {
+                url,
+                width: None,
+                height: None,
+                kind: SiteIconKind::DefaultFavicon,
+            });
+        }
+    }
+    metadata.site_icons = site_icons;
+}
+
+/// Pick the final title once the article heading is known
+///
+/// The title chosen by [`get_article_metadata`] is kept unless it is only site
+/// branding, or it is `heading` plus short branding segments (e.g. "Heading |
+/// Site"). Then the other candidates are ranked by the words they share with
+/// `heading`, and the heading itself wins when none is close enough. With
+/// `strip_branding`, site-name segments are also cut from a title that is kept,
+/// so "Article | Site" becomes "Article" even without a matching heading.
+pub fn select_title(metadata: &mut Metadata, heading: Option<&str>, strip_branding: bool) {
+    let site_name = metadata.site_name.as_deref();
+    let heading = heading
+        .map(|h| utils::normalize_whitespace(h.trim()))
+        .filter(|h| h.chars().filter(|c| c.is_alphanumeric()).count() >= 3)
+        .filter(|h| !is_site_branding(h, site_name));
+
+    let chosen = metadata
+        .title_candidates
+        .first()
+        .filter(|c| !c.text.trim().is_empty());
+
+    let replacement = match (chosen, heading.as_deref()) {
+        (Some(chosen), heading) if is_site_branding(&chosen.text, site_name) => {
+            let mut viable = metadata
+                .title_candidates
+                .iter()
+                .filter(|c| !is_site_branding(&c.text, site_name));
+            match heading {
+                Some(heading) => Some(closest_to_heading(viable, heading)),
+                None => viable.next().cloned(),
+            }
+        }
+        (Some(chosen), Some(heading))
+            if prefers_heading(chosen, heading, strip_branding)
+                && is_branded_heading(&chosen.text, heading, site_name) =>
+        {
+            // The `<title>` segments are what carries the branding, so only
+            // structured and meta titles compete with the heading
+            let viable = metadata.title_candidates.iter().filter(|c| {
+                c.source != TitleSource::Document
+                    && !is_site_branding(&c.text, site_name)
+                    && !is_branded_heading(&c.text, heading, site_name)
+            });
+            Some(closest_to_heading(viable, heading))
+        }
+        (Some(chosen), _) if strip_branding => {
+            strip_site_segments(&chosen.text, site_name).map(|text| TitleCandidate {
+                text,
+                source: chosen.source,
+            })
+        }
+        (Some(_), _) => None,
+        (None, heading) => heading.map(|heading| TitleCandidate {
+            text: heading.to_string(),
+            source: TitleSource::Heading,
+        }),
+    };
+
+    if let Some(candidate) = replacement {
+        metadata.title = Some(candidate.text);
+        metadata.title_source = Some(candidate.source);
+    }
+}
+
+/// Whether a branded `chosen` title should give way to `heading`
+///
+/// Publisher-provided JSON-LD and meta titles are only replaced on request.
+/// Like Readability.js, a `<title>` is kept with its branding when the heading
+/// has four words or fewer ("Mozilla - Wikipedia").
+fn prefers_heading(chosen: &TitleCandidate, heading: &str, strip_branding: bool) -> bool {
+    strip_branding
+        || (chosen.source == TitleSource::Document && heading.split_whitespace().count() > 4)
+}
+
+/// The candidate sharing the most words with `heading`, or the heading itself
+fn closest_to_heading<'a>(
+    candidates: impl Iterator<Item = &'a TitleCandidate>,
+    heading: &str,
+) -> TitleCandidate {
+    candidates
+        .map(|c| (c, title_similarity(&c.text, heading)))
+        .filter(|(_, score)| *score >= 0.5)
+        .fold(
+            None::<(&TitleCandidate, f64)>,
+            |best, (c, score)| match best {
+                Some((_, best_score)) if best_score >= score => best,
+                _ => Some((c, score)),
+            },
+        )
+        .map(|(c, _)| c.clone())
+        .unwrap_or_else(|| TitleCandidate {
+            text: heading.to_string(),
+            source: TitleSource::Heading,
+        })
+}
+
+/// Cut the site-name segments from a title such as "Article | Site"
+fn strip_site_segments(title: &str, site_name: Option<&str>) -> Option<String> {
+    let segments = split_title_segments(title);
+    let mut rest = segments
+        .iter()
+        .filter(|segment| !is_site_branding(segment, site_name));
+    match (segments.len(), rest.next(), rest.next()) {
+        (2.., Some(segment), None) => Some(segment.clone()),
+        _ => None,
+    }
+}
+
+/// Split the raw `<title>` on separators such as `|`, `-` and `»`
+fn document_title_segments(document: &Html) -> Vec<String> {
+    static TITLE_SELECTOR: Lazy<Selector> = Lazy::new(|| Selector::parse("title").unwrap());
+
+    let Some(title) = document.select(&TITLE_SELECTOR).next() else {
+        return Vec::new();
+    };
+    let segments = split_title_segments(&title.text().collect::<String>());
+    if segments.len() < 2 {
+        return Vec::new();
+    }
+    segments
+}
+
+fn split_title_segments(title: &str) -> Vec<String> {
+    static SEPARATOR_REGEX: Lazy<regex::Regex> =
+        Lazy::new(|| regex::Regex::new(r"\s(?:\||\-|–|—|\\|/|>|»|::|·)\s").unwrap());
+
+    SEPARATOR_REGEX
+        .split(title)
+        .map(|segment| utils::normalize_whitespace(segment.trim()))
+        .filter(|segment| !segment.is_empty())
+        .collect()
+}
+
+/// Whether a title is only the site name (or a part of it)
+fn is_site_branding(title: &str, site_name: Option<&str>) -> bool {
+    let Some(site_name) = site_name else {
+        return false;
+    };
+    let title_tokens = title_tokens(title);
+    let site_tokens = title_tokens_set(site_name);
+    !title_tokens.is_empty() && title_tokens.iter().all(|t| site_tokens.contains(t))
+}
+
+/// Whether `title` is `heading` with short branding segments around it
+fn is_branded_heading(title: &str, heading: &str, site_name: Option<&str>) -> bool {
+    let segments = split_title_segments(title);
+    if segments.len() < 2 {
+        return false;
+    }
+
+    segments.iter().enumerate().any(|(index, segment)| {
+        title_similarity(segment, heading) >= 0.8
+            && segments.iter().enumerate().all(|(other, rest)| {
+                other == index
+                    || rest.split_whitespace().count() <= 4
+                    || is_site_branding(rest, site_name)
+            })
+    })
+}
+
+/// Jaccard similarity of the lowercase word sets of two titles
+fn title_similarity(a: &str, b: &str) -> f64 {
+    let a = title_tokens_set(a);
+    let b = title_tokens_set(b);
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    a.intersection(&b).count() as f64 / a.union(&b).count() as f64
+}
+
+fn title_tokens(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn title_tokens_set(text: &str) -> std::collections::HashSet<String> {
+    title_tokens(text).into_iter().collect()
+}
+
+/// Extract the canonical page URL from `link[rel="canonical"]` or `og:url`
+fn extract_canonical_url(document: &Html) -> Option<String> {
+    static CANONICAL_SELECTOR: Lazy<Selector> =
+        Lazy::new(|| Selector::parse("link[rel~='canonical'][href]").unwrap());
+    static OG_URL_SELECTOR: Lazy<Selector> =
+        Lazy::new(|| Selector::parse("meta[property='og:url'][content]").unwrap());
+
+    document
+        .select(&CANONICAL_SELECTOR)
+        .filter_map(|link| link.value().attr("href"))
+        .chain(
+            document
+                .select(&OG_URL_SELECTOR)
+                .filter_map(|meta| meta.value().attr("content")),
+        )
+        .map(str::trim)
+        .find(|url| !url.is_empty())
+        .map(str::to_string)
+}
+
+/// Extract author profile links from `rel="author"` links
+///
+/// Links in comment sections and other non-article contexts are skipped, the
+/// same as for the DOM byline.
+fn extract_author_urls_from_document(document: &Html) -> Vec<String> {
+    static AUTHOR_LINK_SELECTOR: Lazy<Selector> =
+        Lazy::new(|| Selector::parse("link[rel~='author'][href], a[rel~='author'][href]").unwrap());
+
+    let mut urls: Vec<String> = Vec::new();
+    for link in document.select(&AUTHOR_LINK_SELECTOR) {
+        if link.value().name() == "a"
+            && (is_ignorable_byline_context(&link) || is_noise_byline_context(&link))
+        {
+            continue;
+        }
+
+        let href = link.value().attr("href").unwrap_or("").trim();
+        if !href.is_empty() && !href.starts_with('#') && !urls.iter().any(|u| u == href) {
+            urls.push(href.to_string());
+        }
+    }
+    urls
+}
+
 /// Extract image URL from document structure
 ///
 /// Checks additional sources when meta tags don't provide an image:
@@ -517,20 +1377,18 @@ fn extract_image_from_document(document: &Html) -> Option<String> {
 #[derive(Clone, Debug, PartialEq, Eq)]
 struct DomBylineCandidate {
     text: String,
-    confidence: DomBylineConfidence,
+    confidence: BylineConfidence,
+    source: BylineSource,
 }
 
 impl DomBylineCandidate {
-    fn new(text: String, confidence: DomBylineConfidence) -> Self {
-        Self { text, confidence }
-    }
-}
-
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum DomBylineConfidence {
-    High,
-    Medium,
-    Low,
+    fn new(source: BylineSource, text: String, confidence: BylineConfidence) -> Self {
+        Self {
+            text,
+            confidence,
+            source,
+        }
+    }
 }
 
 /// Extract byline/author from document structure
@@ -540,14 +1398,15 @@ enum DomBylineConfidence {
 /// 2. itemprop="author" elements
 /// 3. Common byline CSS classes (.byline, .author, .by, etc.)
 /// 4. <address> tags with author context
-fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
+fn extract_byline_from_document(document: &Html, lang: Option<&str>) -> Option<DomBylineCandidate> {
     use crate::scoring;
 
     let mut fallback_candidate: Option<DomBylineCandidate> = None;
     if let Some(candidate) = extract_standfirst_caps_byline(document) {
         return Some(DomBylineCandidate::new(
+            BylineSource::Standfirst,
             candidate,
-            DomBylineConfidence::High,
+            BylineConfidence::High,
         ));
     }
 
@@ -561,8 +1420,9 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
             }
             if let Some(parent_text) = parent_byline_text(&link) {
                 return Some(DomBylineCandidate::new(
+                    BylineSource::RelAuthor,
                     parent_text,
-                    DomBylineConfidence::High,
+                    BylineConfidence::High,
                 ));
             }
 
@@ -580,8 +1440,9 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
                     match utils::clean_byline_text_with_reason(&text) {
                         utils::CleanBylineOutcome::Accepted(cleaned) => {
                             return Some(DomBylineCandidate::new(
+                                BylineSource::RelAuthor,
                                 cleaned,
-                                DomBylineConfidence::High,
+                                BylineConfidence::High,
                             ))
                         }
                         utils::CleanBylineOutcome::DroppedOrgCredit => return None,
@@ -602,8 +1463,9 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
             }
             if let Some(parent_text) = parent_byline_text(&elem) {
                 return Some(DomBylineCandidate::new(
+                    BylineSource::Itemprop,
                     parent_text,
-                    DomBylineConfidence::High,
+                    BylineConfidence::High,
                 ));
             }
 
@@ -621,8 +1483,9 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
                     match utils::clean_byline_text_with_reason(&text) {
                         utils::CleanBylineOutcome::Accepted(cleaned) => {
                             return Some(DomBylineCandidate::new(
+                                BylineSource::Itemprop,
                                 cleaned,
-                                DomBylineConfidence::High,
+                                BylineConfidence::High,
                             ))
                         }
                         utils::CleanBylineOutcome::DroppedOrgCredit => return None,
@@ -669,18 +1532,19 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
                 let match_string = format!("{class} {id}");
 
                 if scoring::is_valid_byline(elem, &match_string)
-                    || utils::looks_like_byline(&text)
+                    || utils::looks_like_localized_byline(&text, lang)
                     || text_is_caps
                 {
                     let confidence = if element_has_explicit_byline_marker(&elem) {
-                        DomBylineConfidence::High
+                        BylineConfidence::High
                     } else {
-                        DomBylineConfidence::Medium
+                        BylineConfidence::Medium
                     };
                     match utils::clean_byline_text_with_reason(&text) {
                         utils::CleanBylineOutcome::Accepted(cleaned) => {
-                            let candidate = DomBylineCandidate::new(cleaned, confidence);
-                            if is_priority_dom_candidate(&candidate, text_is_caps) {
+                            let candidate =
+                                DomBylineCandidate::new(BylineSource::Class, cleaned, confidence);
+                            if is_priority_dom_candidate(&candidate, text_is_caps, lang) {
                                 return Some(candidate);
                             } else if fallback_candidate.is_none() {
                                 fallback_candidate = Some(candidate);
@@ -724,14 +1588,17 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
             let text_is_caps = looks_like_caps_author(&text);
             let match_string = format!("{class} {id}");
             if scoring::is_valid_byline(elem, &match_string)
-                || utils::looks_like_byline(&text)
+                || utils::looks_like_localized_byline(&text, lang)
                 || text_is_caps
             {
                 match utils::clean_byline_text_with_reason(&text) {
                     utils::CleanBylineOutcome::Accepted(cleaned) => {
-                        let candidate =
-                            DomBylineCandidate::new(cleaned, DomBylineConfidence::Medium);
-                        if is_priority_dom_candidate(&candidate, text_is_caps) {
+                        let candidate = DomBylineCandidate::new(
+                            BylineSource::Class,
+                            cleaned,
+                            BylineConfidence::Medium,
+                        );
+                        if is_priority_dom_candidate(&candidate, text_is_caps, lang) {
                             return Some(candidate);
                         } else if fallback_candidate.is_none() {
                             fallback_candidate = Some(candidate);
@@ -759,14 +1626,18 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
             }
 
             let text_is_caps = looks_like_caps_author(&text);
-            if utils::looks_like_byline(&text)
+            if utils::looks_like_localized_byline(&text, lang)
                 || scoring::is_valid_byline(elem, &text)
                 || text_is_caps
             {
                 match utils::clean_byline_text_with_reason(&text) {
                     utils::CleanBylineOutcome::Accepted(cleaned) => {
-                        let candidate = DomBylineCandidate::new(cleaned, DomBylineConfidence::Low);
-                        if is_priority_dom_candidate(&candidate, text_is_caps) {
+                        let candidate = DomBylineCandidate::new(
+                            BylineSource::Address,
+                            cleaned,
+                            BylineConfidence::Low,
+                        );
+                        if is_priority_dom_candidate(&candidate, text_is_caps, lang) {
                             return Some(candidate);
                         } else if fallback_candidate.is_none() {
                             fallback_candidate = Some(candidate);
@@ -797,11 +1668,15 @@ fn extract_byline_from_document(document: &Html) -> Option<DomBylineCandidate> {
             }
 
             let text_is_caps = looks_like_caps_author(&text);
-            if utils::looks_like_byline(&text) || text_is_caps {
+            if utils::looks_like_localized_byline(&text, lang) || text_is_caps {
                 match utils::clean_byline_text_with_reason(&text) {
                     utils::CleanBylineOutcome::Accepted(cleaned) => {
-                        let candidate = DomBylineCandidate::new(cleaned, DomBylineConfidence::Low);
-                        if is_priority_dom_candidate(&candidate, text_is_caps) {
+                        let candidate = DomBylineCandidate::new(
+                            BylineSource::Text,
+                            cleaned,
+                            BylineConfidence::Low,
+                        );
+                        if is_priority_dom_candidate(&candidate, text_is_caps, lang) {
                             return Some(candidate);
                         } else if fallback_candidate.is_none() {
                             fallback_candidate = Some(candidate);
@@ -853,6 +1728,162 @@ fn extract_standfirst_caps_byline(document: &Html) -> Option<String> {
     None
 }
 
+/// Combine the schema publisher with the site name, app name and social meta tags
+///
+/// The first name found becomes [`Publisher::name`] and the other distinct
+/// names its aliases. Returns `None` when the page says nothing about its
+/// publisher.
+fn build_publisher(
+    document: &Html,
+    schema: Option<Publisher>,
+    site_names: [Option<&String>; 2],
+) -> Option<Publisher> {
+    let mut publisher = schema.unwrap_or_default();
+    let schema_name = publisher.name.take();
+
+    let names = site_names
+        .into_iter()
+        .flatten()
+        .cloned()
+        .chain(schema_name)
+        .chain(meta_content(document, "application-name"))
+        .chain(meta_content(document, "apple-mobile-web-app-title"));
+    for name in names {
+        let name = utils::normalize_whitespace(utils::unescape_html_entities(&name).trim());
+        if name.is_empty() || publisher.names().any(|n| n.eq_ignore_ascii_case(&name)) {
+            continue;
+        }
+        match publisher.name {
+            None => publisher.name = Some(name),
+            Some(_) => publisher.aliases.push(name),
+        }
+    }
+
+    publisher.twitter_handle =
+        meta_content(document, "twitter:site").and_then(|site| normalize_twitter_handle(&site));
+    publisher.facebook_pages = meta_content(document, "fb:pages")
+        .map(|pages| {
+            pages
+                .split(',')
+                .map(str::trim)
+                .filter(|id| !id.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    (publisher != Publisher::default()).then_some(publisher)
+}
+
+/// Content of the first `<meta>` whose `name` or `property` is `key`
+fn meta_content(document: &Html, key: &str) -> Option<String> {
+    static META_SELECTOR: Lazy<Selector> = Lazy::new(|| Selector::parse("meta[content]").unwrap());
+
+    document
+        .select(&META_SELECTOR)
+        .filter(|meta| {
+            let value = meta.value();
+            [value.attr("name"), value.attr("property")]
+                .into_iter()
+                .flatten()
+                .any(|attr| attr.trim().eq_ignore_ascii_case(key))
+        })
+        .filter_map(|meta| meta.value().attr("content"))
+        .map(str::trim)
+        .find(|content| !content.is_empty())
+        .map(str::to_string)
+}
+
+/// Normalize `twitter:site` (`nytimes`, `@nytimes` or a profile URL) to `@nytimes`
+fn normalize_twitter_handle(site: &str) -> Option<String> {
+    let site = site.trim();
+    let handle = match site
+        .split_once("twitter.com/")
+        .or_else(|| site.split_once("x.com/"))
+    {
+        Some((_, path)) => path.split(['/', '?', '#']).next().unwrap_or(""),
+        None => site,
+    };
+    let handle = handle.trim_start_matches('@');
+    if handle.is_empty()
+        || !handle
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return None;
+    }
+    Some(format!("@{handle}"))
+}
+
+/// Find the standfirst/dek below the headline
+///
+/// Checks the usual dek classes first, then an `<h2>` that directly follows
+/// the first `<h1>`. Bylines nested in a standfirst are left out of its text.
+fn extract_subtitle_from_document(document: &Html) -> Option<String> {
+    static DEK_SELECTOR: Lazy<Selector> = Lazy::new(|| {
+        Selector::parse(
+            ".standfirst, .dek, .subtitle, .subheadline, .sub-headline, .article-dek, \
+             .article-subtitle, .article-standfirst, [itemprop~='alternativeHeadline']",
+        )
+        .unwrap()
+    });
+    static H1_SELECTOR: Lazy<Selector> = Lazy::new(|| Selector::parse("h1").unwrap());
+
+    let dek = document
+        .select(&DEK_SELECTOR)
+        .filter(|elem| !is_noise_byline_context(elem))
+        .map(subtitle_text)
+        .find(|text| is_plausible_subtitle(text));
+    if dek.is_some() {
+        return dek;
+    }
+
+    let h1 = document.select(&H1_SELECTOR).next()?;
+    let next = h1.next_siblings().find_map(ElementRef::wrap)?;
+    if !next.value().name().eq_ignore_ascii_case("h2") {
+        return None;
+    }
+    Some(subtitle_text(next)).filter(|text| is_plausible_subtitle(text))
+}
+
+/// Text of a dek element, skipping byline and author children
+fn subtitle_text(element: ElementRef) -> String {
+    fn is_byline(element: &ElementRef) -> bool {
+        let value = element.value();
+        if value.attr("rel") == Some("author") || value.attr("itemprop") == Some("author") {
+            return true;
+        }
+        let class = value.attr("class").unwrap_or("").to_lowercase();
+        class.contains("byline") || class.contains("author")
+    }
+
+    fn collect(element: ElementRef, out: &mut String) {
+        for child in element.children() {
+            match child.value() {
+                Node::Text(text) => out.push_str(text),
+                Node::Element(data) => {
+                    if data.name().eq_ignore_ascii_case("br") {
+                        out.push(' ');
+                    }
+                    if let Some(child) = ElementRef::wrap(child).filter(|c| !is_byline(c)) {
+                        collect(child, out);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut text = String::new();
+    collect(element, &mut text);
+    utils::normalize_whitespace(text.trim())
+}
+
+fn is_plausible_subtitle(text: &str) -> bool {
+    let words = text.split_whitespace().count();
+    (3..=80).contains(&words)
+}
+
 fn build_byline_text(element: &ElementRef) -> String {
     fn append_children_text(element: &ElementRef, out: &mut String) {
         for child in element.children() {
@@ -1087,7 +2118,7 @@ const MONTH_KEYWORDS: [&str; 24] = [
     "december",
 ];
 
-fn should_prefer_dom_byline(existing: &str, dom: &str, confidence: DomBylineConfidence) -> bool {
+fn should_prefer_dom_byline(existing: &str, dom: &str, confidence: BylineConfidence) -> bool {
     let existing_clean = existing.trim();
     let dom_clean = dom.trim();
 
@@ -1103,7 +2134,7 @@ fn should_prefer_dom_byline(existing: &str, dom: &str, confidence: DomBylineConf
         return true;
     }
 
-    if confidence == DomBylineConfidence::High
+    if confidence == BylineConfidence::High
         && looks_like_caps_author(dom_clean)
         && !looks_like_caps_author(existing_clean)
     {
@@ -1253,8 +2284,12 @@ fn element_has_explicit_byline_marker(element: &ElementRef) -> bool {
     class.contains("byline") || id.contains("byline")
 }
 
-fn is_priority_dom_candidate(candidate: &DomBylineCandidate, raw_caps: bool) -> bool {
-    raw_caps || utils::looks_like_byline(&candidate.text)
+fn is_priority_dom_candidate(
+    candidate: &DomBylineCandidate,
+    raw_caps: bool,
+    lang: Option<&str>,
+) -> bool {
+    raw_caps || utils::looks_like_localized_byline(&candidate.text, lang)
 }
 
 fn ancestor_has_keyword(element: &ElementRef, keywords: &[&str], max_depth: usize) -> bool {
@@ -1361,15 +2396,12 @@ fn is_noise_byline_context(element: &ElementRef) -> bool {
 /// 2. Content-Language meta tag
 /// 3. http-equiv="Content-Language"
 fn extract_language_from_document(document: &Html) -> Option<String> {
-    if let Some(html_elem) = document.root_element().first_child() {
-        if let Some(node_ref) = scraper::ElementRef::wrap(html_elem) {
-            if node_ref.value().name() == "html" {
-                if let Some(lang) = node_ref.value().attr("lang") {
-                    let lang = lang.trim();
-                    if !lang.is_empty() {
-                        return Some(lang.to_string());
-                    }
-                }
+    let html_elem = document.root_element();
+    if html_elem.value().name() == "html" {
+        if let Some(lang) = html_elem.value().attr("lang") {
+            let lang = lang.trim();
+            if !lang.is_empty() {
+                return Some(lang.to_string());
             }
         }
     }
@@ -1522,6 +2554,116 @@ mod tests {
         assert_eq!(metadata.excerpt, Some("Test description".to_string()));
     }
 
+    #[test]
+    fn test_json_ld_paywall_access() {
+        let html = r#"
+            <html>
+                <head>
+                    <script type="application/ld+json">
+                    {
+                        "@context": "https://schema.org",
+                        "@type": "NewsArticle",
+                        "headline": "Locked Article",
+                        "isAccessibleForFree": "False",
+                        "hasPart": {
+                            "@type": "WebPageElement",
+                            "isAccessibleForFree": "False",
+                            "cssSelector": ".paywall"
+                        }
+                    }
+                    </script>
+                </head>
+            </html>
+        "#;
+
+        let document = Html::parse_document(html);
+        let json_ld = get_json_ld(&document);
+        assert_eq!(json_ld.access, Some(ArticleAccess::SubscriberOnly));
+
+        let metered = html.replace(
+            "<head>",
+            r#"<head><meta property="article:content_tier" content="metered">"#,
+        );
+        let document = Html::parse_document(&metered);
+        let metadata = get_article_metadata(&document, get_json_ld(&document));
+        assert_eq!(metadata.access, Some(ArticleAccess::Metered));
+
+        let free =
+            r#"<html><head><meta property="article:content_tier" content="free"></head></html>"#;
+        let document = Html::parse_document(free);
+        let metadata = get_article_metadata(&document, get_json_ld(&document));
+        assert_eq!(metadata.access, Some(ArticleAccess::Free));
+    }
+
+    #[test]
+    fn test_resolve_metadata_urls() {
+        let html = r#"
+            <html>
+                <head>
+                    <base href="/news/">
+                    <meta property="og:image" content="//cdn.example.com/lead.jpg">
+                    <link rel="canonical" href="story-1.html">
+                </head>
+                <body>
+                    <a rel="author" href="/people/jane">Jane Doe</a>
+                    <a rel="author" href="http://[broken">Jane Doe</a>
+                </body>
+            </html>
+        "#;
+
+        let document = Html::parse_document(html);
+        let mut metadata = get_article_metadata(&document, Metadata::default());
+        assert_eq!(
+            metadata.image.as_deref(),
+            Some("//cdn.example.com/lead.jpg")
+        );
+        assert_eq!(metadata.canonical_url.as_deref(), Some("story-1.html"));
+
+        let page_url = "https://example.com/index.html";
+        let base_url = crate::dom_utils::get_document_base_url(&document, page_url);
+        resolve_metadata_urls(&mut metadata, &base_url, page_url);
+
+        assert_eq!(
+            metadata.image.as_deref(),
+            Some("https://cdn.example.com/lead.jpg")
+        );
+        assert_eq!(
+            metadata.canonical_url.as_deref(),
+            Some("https://example.com/news/story-1.html")
+        );
+        assert_eq!(metadata.author_urls, ["https://example.com/people/jane"]);
+        assert_eq!(metadata.site_icons.len(), 1);
+        assert_eq!(metadata.site_icons[0].kind, SiteIconKind::DefaultFavicon);
+        assert_eq!(
+            metadata.site_icons[0].url,
+            "https://example.com/favicon.ico"
+        );
+    }
+
+    #[test]
+    fn test_default_favicon_ignores_base_href() {
+        let html = r#"<html><head>
+            <base href="https://cdn.example.net/assets/">
+            <link rel="canonical" href="story.html">
+            </head><body></body></html>"#;
+        let document = Html::parse_document(html);
+        let mut metadata = get_article_metadata(&document, Metadata::default());
+
+        let page_url = "https://example.com/news/story.html";
+        let base_url = crate::dom_utils::get_document_base_url(&document, page_url);
+        resolve_metadata_urls(&mut metadata, &base_url, page_url);
+
+        assert_eq!(
+            metadata.canonical_url.as_deref(),
+            Some("https://cdn.example.net/assets/story.html")
+        );
+        assert_eq!(metadata.site_icons.len(), 1);
+        assert_eq!(
+            metadata.site_icons[0].url,
+            "https://example.com/favicon.ico"
+        );
+    }
+
     #[test]
     fn test_json_ld_image_extraction() {
         let html = r#"
@@ -1790,6 +2932,146 @@ mod tests {
         assert_eq!(metadata.byline, Some("Hazel Sheffield".to_string()));
     }
 
+    #[test]
+    fn test_microdata_extraction() {
+        let html = r#"
+            <html>
+                <body>
+                    <div itemscope itemtype="http://schema.org/NewsArticle">
+                        <h1 itemprop="headline">Microdata Headline</h1>
+                        <span itemprop="author" itemscope itemtype="http://schema.org/Person">
+                            <a itemprop="url" href="/staff/jane"><span itemprop="name">Jane Smith</span></a>
+                        </span>
+                        <time itemprop="datePublished" datetime="2020-05-01T08:00:00Z">May 1</time>
+                        <img itemprop="image" src="https://example.com/lead.jpg" />
+                        <div itemprop="publisher" itemscope itemtype="http://schema.org/Organization">
+                            <meta itemprop="name" content="Example Times" />
+                        </div>
+                    </div>
+                </body>
+            </html>
+        "#;
+
+        let document = Html::parse_document(html);
+        let microdata = get_microdata(&document);
+
+        assert_eq!(microdata.title, Some("Microdata Headline".to_string()));
+        assert_eq!(microdata.byline, Some("Jane Smith".to_string()));
+        assert_eq!(
+            microdata.published_time,
+            Some("2020-05-01T08:00:00Z".to_string())
+        );
+        assert_eq!(
+            microdata.image,
+            Some("https://example.com/lead.jpg".to_string())
+        );
+        assert_eq!(microdata.site_name, None);
+        assert_eq!(
+            microdata.publisher.and_then(|p| p.name),
+            Some("Example Times".to_string())
+        );
+    }
+
+    #[test]
+    fn test_microdata_ignores_footer_author() {
+        let html = r#"
+            <html>
+                <body>
+                    <div itemscope itemtype="http://schema.org/BlogPosting">
+                        <h3 itemprop="name">Post title</h3>
+                        <div itemprop="articleBody"><p>Post body.</p></div>
+                        <div class="post-footer">
+                            <span itemprop="author" itemscope itemtype="http://schema.org/Person">
+                                <span itemprop="name">Blog Owner</span>
+                            </span>
+                        </div>
+                    </div>
+                </body>
+            </html>
+        "#;
+
+        let document = Html::parse_document(html);
+        let microdata = get_microdata(&document);
+        assert_eq!(microdata.title, Some("Post title".to_string()));
+        assert_eq!(microdata.byline, None);
+    }
+
+    #[test]
+    fn test_microdata_repeated_agency_author() {
+        let html = r#"
+            <html>
+                <body>
+                    <article itemscope itemtype="http://schema.org/NewsArticle">
+                        <h1 itemprop="headline">Un troisième Français mort</h1>
+                        <div class="info">
+                            <span class="author" itemprop="author creator" itemscope
+                                itemtype="http://schema.org/Person"><a href="/auteur/2005-afp"><span
+                                itemprop="name"> AFP</span></a></span>
+                        </div>
+                        <div class="authors-container">
+                            <span class="author" itemprop="author creator" itemscope
+                                itemtype="http://schema.org/Person"><a href="/auteur/2005-afp"><span
+                                itemprop="name"> AFP</span></a></span>
+                        </div>
+                    </article>
+                </body>
+            </html>
+        "#;
+
+        let document = Html::parse_document(html);
+        let microdata = get_microdata(&document);
+        assert_eq!(microdata.byline, None);
+
+        let html = html.replace(" AFP", " Sébastien Farcis");
+        let microdata = get_microdata(&Html::parse_document(&html));
+        assert_eq!(microdata.byline.as_deref(), Some("Sébastien Farcis"));
+    }
+
+    #[test]
+    fn test_rdfa_extraction() {
+        let html = r#"
+            <html>
+                <body vocab="https://schema.org/">
+                    <article typeof="BlogPosting">
+                        <h1 property="headline">RDFa Headline</h1>
+                        <p>By <span property="author">Alex Doe</span></p>
+                        <meta property="datePublished" content="2021-02-03" />
+                    </article>
+                </body>
+            </html>
+        "#;
+
+        let document = Html::parse_document(html);
+        let microdata = get_microdata(&document);
+
+        assert_eq!(microdata.title, Some("RDFa Headline".to_string()));
+        assert_eq!(microdata.byline, Some("Alex Doe".to_string()));
+        assert_eq!(microdata.published_time, Some("2021-02-03".to_string()));
+    }
+
+    #[test]
+    fn test_microdata_fills_gaps_after_meta_tags() {
+        let html = r#"
+            <html>
+                <head>
+                    <meta property="og:title" content="OG Title" />
+                </head>
+                <body>
+                    <div itemscope itemtype="https://schema.org/Article">
+                        <h1 itemprop="headline">Microdata Headline</h1>
+                        <time itemprop="datePublished" datetime="2019-09-09">Sep 9</time>
+                    </div>
+                </body>
+            </html>
+        "#;
+
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, Metadata::default());
+
+        assert_eq!(metadata.title, Some("OG Title".to_string()));
+        assert_eq!(metadata.published_time, Some("2019-09-09".to_string()));
+    }
+
     #[test]
     fn test_title_extraction() {
         let html = r#"
@@ -1810,6 +3092,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::len_zero)]
     fn test_title_extraction_colon() {
         let html = r#"
             <html>
@@ -2026,7 +3309,7 @@ mod tests {
         }
         assert!(saw_lucas, "expected to find Lucas Nolan byline candidate");
 
-        let dom_byline = extract_byline_from_document(&document);
+        let dom_byline = extract_byline_from_document(&document, None);
         assert!(
             dom_byline.is_some(),
             "expected Breitbart byline to be detected"
@@ -2037,7 +3320,7 @@ mod tests {
     fn test_cnet_authorinfo_is_extracted() {
         let html = fs::read_to_string("tests/test-pages/cnet/source.html").unwrap();
         let document = Html::parse_document(&html);
-        let dom_byline = extract_byline_from_document(&document).map(|c| c.text);
+        let dom_byline = extract_byline_from_document(&document, None).map(|c| c.text);
         assert_eq!(dom_byline, Some("Steven Musil".to_string()));
     }
 
@@ -2045,9 +3328,9 @@ mod tests {
     fn test_herald_sun_caps_byline_overrides_meta() {
         let html = fs::read_to_string("tests/test-pages/herald-sun-1/source.html").unwrap();
         let document = Html::parse_document(&html);
-        let dom_byline = extract_byline_from_document(&document).expect("dom byline");
+        let dom_byline = extract_byline_from_document(&document, None).expect("dom byline");
         assert_eq!(dom_byline.text, "JOE HILDEBRAND");
-        assert_eq!(dom_byline.confidence, DomBylineConfidence::High);
+        assert_eq!(dom_byline.confidence, BylineConfidence::High);
         assert!(
             should_prefer_dom_byline("by: Laurie Oakes", &dom_byline.text, dom_byline.confidence),
             "dom byline should override Laurie Oakes"
@@ -2106,6 +3389,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn test_wapo_byline_is_detected() {
         let html = fs::read_to_string("tests/test-pages/wapo-1/source.html").unwrap();
         let document = Html::parse_document(&html);
@@ -2121,7 +3405,255 @@ mod tests {
             "pb-byline text was {:?}",
             text
         );
-        let dom_byline = extract_byline_from_document(&document).expect("should detect DOM byline");
+        let dom_byline =
+            extract_byline_from_document(&document, None).expect("should detect DOM byline");
         assert_eq!(dom_byline.text, "By Erin Cunningham");
     }
+
+    #[test]
+    fn test_select_title_replaces_site_name() {
+        let html = r#"<html><head>
+            <title>Example Weekly</title>
+            <meta property="og:site_name" content="Example Weekly">
+            </head><body></body></html>"#;
+        let document = Html::parse_document(html);
+        let mut metadata = get_article_metadata(&document, Metadata::default());
+        assert_eq!(metadata.title_source, Some(TitleSource::Document));
+
+        select_title(&mut metadata, Some("Why the river flooded twice"), false);
+        assert_eq!(
+            metadata.title.as_deref(),
+            Some("Why the river flooded twice")
+        );
+        assert_eq!(metadata.title_source, Some(TitleSource::Heading));
+    }
+
+    #[test]
+    fn test_select_title_branding_suffix() {
+        let html = r#"<html><head>
+            <title>Why the river flooded twice | Example Weekly | Subscribe</title>
+            <meta property="og:site_name" content="Example Weekly">
+            </head><body></body></html>"#;
+        let document = Html::parse_document(html);
+        let mut metadata = get_article_metadata(&document, Metadata::default());
+        assert_eq!(
+            metadata.title.as_deref(),
+            Some("Why the river flooded twice | Example Weekly")
+        );
+        select_title(&mut metadata, Some("Why the river flooded twice"), false);
+        assert_eq!(
+            metadata.title.as_deref(),
+            Some("Why the river flooded twice")
+        );
+        assert_eq!(metadata.title_source, Some(TitleSource::Heading));
+
+        // Short titles keep their branding unless it is stripped on request
+        let html = r#"<html><head>
+            <title>River floods | Example Weekly</title>
+            <meta property="og:site_name" content="Example Weekly">
+            </head><body></body></html>"#;
+        let document = Html::parse_document(html);
+        let heading = Some("River floods");
+
+        let mut metadata = get_article_metadata(&document, Metadata::default());
+        select_title(&mut metadata, heading, false);
+        assert_eq!(
+            metadata.title.as_deref(),
+            Some("River floods | Example Weekly")
+        );
+        assert_eq!(metadata.title_source, Some(TitleSource::Document));
+
+        let mut metadata = get_article_metadata(&document, Metadata::default());
+        select_title(&mut metadata, heading, true);
+        assert_eq!(metadata.title.as_deref(), Some("River floods"));
+        assert_eq!(metadata.title_source, Some(TitleSource::Heading));
+
+        let mut metadata = get_article_metadata(&document, Metadata::default());
+        select_title(&mut metadata, None, true);
+        assert_eq!(metadata.title.as_deref(), Some("River floods"));
+        assert_eq!(metadata.title_source, Some(TitleSource::Document));
+    }
+
+    #[test]
+    fn test_select_title_ranks_by_heading_overlap() {
+        let html = r#"<html><head>
+            <script type="application/ld+json">
+            {"@context": "https://schema.org", "@type": "NewsArticle",
+             "headline": "Why the river flooded twice | Example Weekly"}
+            </script>
+            <meta property="og:title" content="Why the river flooded twice this spring">
+            <meta property="og:site_name" content="Example Weekly">
+            </head><body></body></html>"#;
+        let document = Html::parse_document(html);
+        let mut metadata = get_article_metadata(&document, get_json_ld(&document));
+        assert_eq!(metadata.title_source, Some(TitleSource::JsonLd));
+
+        // A branded headline is kept unless branding is stripped
+        let heading = Some("Why the river flooded twice");
+        select_title(&mut metadata, heading, false);
+        assert_eq!(metadata.title_source, Some(TitleSource::JsonLd));
+
+        // og:title shares most of its words with the heading and beats the headline
+        select_title(&mut metadata, heading, true);
+        assert_eq!(
+            metadata.title.as_deref(),
+            Some("Why the river flooded twice this spring")
+        );
+        assert_eq!(metadata.title_source, Some(TitleSource::Meta));
+    }
+
+    #[test]
+    fn test_subtitle_extraction() {
+        let html = r#"<html><head>
+            <script type="application/ld+json">
+            {"@context": "https://schema.org", "@type": "NewsArticle",
+             "headline": "Bridge to be rebuilt",
+             "alternativeHeadline": "The council voted on Tuesday to rebuild the old bridge"}
+            </script>
+            </head><body></body></html>"#;
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, get_json_ld(&document));
+        assert_eq!(
+            metadata.subtitle.as_deref(),
+            Some("The council voted on Tuesday to rebuild the old bridge")
+        );
+
+        let html = r#"<html><body>
+            <h1>Bridge to be rebuilt</h1>
+            <div class="standfirst">
+                <p>The council voted on Tuesday to rebuild the old bridge</p>
+                <em class="byline">By JANE SMITH</em>
+            </div>
+            </body></html>"#;
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, get_json_ld(&document));
+        assert_eq!(
+            metadata.subtitle.as_deref(),
+            Some("The council voted on Tuesday to rebuild the old bridge")
+        );
+
+        let html = r#"<html><body>
+            <h1>Bridge to be rebuilt</h1>
+            <h2>Work starts in the spring and will close the road</h2>
+            </body></html>"#;
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, get_json_ld(&document));
+        assert_eq!(
+            metadata.subtitle.as_deref(),
+            Some("Work starts in the spring and will close the road")
+        );
+    }
+
+    #[test]
+    fn test_publisher_extraction() {
+        let html = r#"<html><head>
+            <meta property="og:site_name" content="The Daily Example">
+            <meta name="application-name" content="Daily Example">
+            <meta name="twitter:site" content="https://twitter.com/dailyexample">
+            <meta property="fb:pages" content="1234, 5678">
+            <script type="application/ld+json">
+            {"@context": "https://schema.org", "@type": "NewsArticle",
+             "headline": "Bridge to be rebuilt",
+             "publisher": {"@type": "Organization", "name": "Example Media Group",
+                           "url": "https://example.com/",
+                           "logo": {"@type": "ImageObject", "url": "https://example.com/logo.png"}}}
+            </script>
+            </head><body></body></html>"#;
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, get_json_ld(&document));
+        let publisher = metadata.publisher.unwrap();
+
+        assert_eq!(publisher.name.as_deref(), Some("Example Media Group"));
+        assert_eq!(
+            publisher.aliases,
+            vec!["The Daily Example", "Daily Example"]
+        );
+        assert_eq!(publisher.url.as_deref(), Some("https://example.com/"));
+        assert_eq!(
+            publisher.logo.as_deref(),
+            Some("https://example.com/logo.png")
+        );
+        assert_eq!(publisher.twitter_handle.as_deref(), Some("@dailyexample"));
+        assert_eq!(publisher.facebook_pages, vec!["1234", "5678"]);
+    }
+
+    #[test]
+    fn test_byline_kept_despite_publisher_alias() {
+        let html = r#"<html><head>
+            <meta property="og:site_name" content="Example">
+            <meta name="apple-mobile-web-app-title" content="Recipes by Jane Smith">
+            <meta name="author" content="Jane Smith">
+            </head><body></body></html>"#;
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, get_json_ld(&document));
+        assert_eq!(metadata.byline.as_deref(), Some("Jane Smith"));
+        assert!(metadata
+            .publisher
+            .unwrap()
+            .aliases
+            .contains(&"Recipes by Jane Smith".to_string()));
+    }
+
+    #[test]
+    fn test_byline_source_and_confidence() {
+        let byline_origin = |html: &str| {
+            let document = Html::parse_document(html);
+            let metadata = get_article_metadata(&document, get_json_ld(&document));
+            (
+                metadata.byline,
+                metadata.byline_source,
+                metadata.byline_confidence,
+            )
+        };
+
+        let (byline, source, confidence) = byline_origin(
+            r#"<html><head><script type="application/ld+json">
+            {"@context": "https://schema.org", "@type": "Article",
+             "headline": "Bridge to be rebuilt", "author": {"name": "Jane Smith"}}
+            </script></head><body></body></html>"#,
+        );
+        assert_eq!(byline.as_deref(), Some("Jane Smith"));
+        assert_eq!(source, Some(BylineSource::JsonLd));
+        assert_eq!(confidence, Some(BylineConfidence::High));
+
+        let (byline, source, confidence) = byline_origin(
+            r#"<html><head><meta name="author" content="Jane Smith"></head><body></body></html>"#,
+        );
+        assert_eq!(byline.as_deref(), Some("Jane Smith"));
+        assert_eq!(source, Some(BylineSource::Meta));
+        assert_eq!(confidence, Some(BylineConfidence::Medium));
+
+        let (byline, source, confidence) = byline_origin(
+            r#"<html><body><article>
+            <p class="meta">By <a rel="author" href="/jane">Jane Smith</a></p>
+            </article></body></html>"#,
+        );
+        assert!(byline.unwrap().contains("Jane Smith"));
+        assert_eq!(source, Some(BylineSource::RelAuthor));
+        assert_eq!(confidence, Some(BylineConfidence::High));
+
+        let (byline, source, confidence) = byline_origin("<html><body></body></html>");
+        assert_eq!((byline, source, confidence), (None, None, None));
+    }
+
+    #[test]
+    fn test_localized_dom_byline() {
+        let html = r#"<html lang="de"><body><article>
+            <p class="meta">Von Max Mustermann | 12.03.2024, 14:30 Uhr</p>
+            </article></body></html>"#;
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, get_json_ld(&document));
+        assert_eq!(metadata.byline.as_deref(), Some("Max Mustermann"));
+        assert_eq!(metadata.byline_source, Some(BylineSource::Text));
+    }
+
+    #[test]
+    fn test_italian_surname_particle_kept() {
+        let html = r#"<html lang="it"><head>
+            <meta name="author" content="Di Maio Luigi">
+            </head><body></body></html>"#;
+        let document = Html::parse_document(html);
+        let metadata = get_article_metadata(&document, get_json_ld(&document));
+        assert_eq!(metadata.byline.as_deref(), Some("Di Maio Luigi"));
+    }
 }
@@ -154,6 +154,143 @@ pub struct ReadabilityOptions {
     /// Default: `false`
     pub remove_title_from_content: bool,
 
+    /// Remove the subtitle (standfirst/dek) element from the extracted content.
+    ///
+    /// When `true`, removes the element whose text matches [`Article::subtitle`]
+    /// from the article content HTML, for layouts that render the dek separately.
+    ///
+    /// [`Article::subtitle`]: crate::Article::subtitle
+    ///
+    /// Default: `false`
+    pub remove_subtitle_from_content: bool,
+
+    /// Strip site branding from the title.
+    ///
+    /// A `<title>` such as "Article Name | Site" is always replaced by the
+    /// article's own heading when the heading matches one of its segments and
+    /// is longer than four words. When `true`, this also applies to short
+    /// headings and to JSON-LD and meta titles, and site-name segments are cut
+    /// from the title even when no heading matches.
+    ///
+    /// Default: `false`
+    pub strip_title_branding: bool,
+
+    /// CSS selectors for the article root, in priority order.
+    ///
+    /// When one of them matches an element with text, that element is used as the
+    /// article content instead of the highest-scoring candidate. Cleaning and
+    /// post-processing still run as usual. When none match, the scoring heuristics
+    /// are used. Invalid selectors make [`Readability::new`] fail with
+    /// [`ReadabilityError::InvalidSelector`].
+    ///
+    /// [`Readability::new`]: crate::Readability::new
+    /// [`ReadabilityError::InvalidSelector`]: crate::ReadabilityError::InvalidSelector
+    ///
+    /// Default: empty
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use readabilityrs::ReadabilityOptions;
+    ///
+    /// let options = ReadabilityOptions::builder()
+    ///     .content_selector("article .story-body")
+    ///     .content_selector("#main-content")
+    ///     .build();
+    /// assert_eq!(options.content_selectors.len(), 2);
+    /// ```
+    pub content_selectors: Vec<String>,
+
+    /// Add qualifying siblings of the element matched by [`content_selectors`].
+    ///
+    /// When `true`, the matched element goes through the same sibling aggregation as
+    /// a scored best candidate, picking up related paragraphs next to it. When
+    /// `false`, only the matched element is used.
+    ///
+    /// [`content_selectors`]: ReadabilityOptions::content_selectors
+    ///
+    /// Default: `false`
+    pub content_selector_siblings: bool,
+
+    /// CSS selectors for elements that are always removed.
+    ///
+    /// Matching elements are dropped from the document before candidate scoring and
+    /// again from the final content, for boxes the cleaning heuristics keep because
+    /// they read like normal text: newsletter signups, "Read more" teasers,
+    /// affiliate disclosures. Invalid selectors make [`Readability::new`] fail with
+    /// [`ReadabilityError::InvalidSelector`].
+    ///
+    /// [`Readability::new`]: crate::Readability::new
+    /// [`ReadabilityError::InvalidSelector`]: crate::ReadabilityError::InvalidSelector
+    ///
+    /// Default: empty
+    pub strip_selectors: Vec<String>,
+
+    /// What to do with semantic HTML5 elements in the extracted content.
+    ///
+    /// Each element can be kept, unwrapped (children kept, tag removed) or dropped.
+    /// Figures, pull quotes, definition lists and `<details>` next to the article body
+    /// are also picked up during sibling aggregation unless their policy is
+    /// [`ElementPolicy::Drop`]. See [`SemanticElementPolicy`] for the defaults.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use readabilityrs::{ElementPolicy, ReadabilityOptions, SemanticElementPolicy};
+    ///
+    /// let options = ReadabilityOptions::builder()
+    ///     .semantic_elements(SemanticElementPolicy {
+    ///         time: ElementPolicy::Unwrap,
+    ///         details: ElementPolicy::Drop,
+    ///         ..Default::default()
+    ///     })
+    ///     .build();
+    /// ```
+    pub semantic_elements: SemanticElementPolicy,
+
+    /// How responsive images (`srcset` and `sizes`) in the extracted content are handled.
+    ///
+    /// By default `srcset` is kept, with every candidate URL resolved against the page
+    /// URL. Clients that can only show one URL per image can collapse each `<img>` and
+    /// `<picture>` to a single `src`, picked for a viewport width or a pixel density.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use readabilityrs::{ReadabilityOptions, SrcsetSelection};
+    ///
+    /// // Pick the images a 600px wide e-ink screen would load
+    /// let options = ReadabilityOptions::builder()
+    ///     .srcset_selection(SrcsetSelection::Width(600))
+    ///     .build();
+    /// ```
+    ///
+    /// Default: [`SrcsetSelection::Keep`]
+    pub srcset_selection: SrcsetSelection,
+
+    /// How math rendered by MathJax, KaTeX or MediaWiki is normalized.
+    ///
+    /// Rendered math is mostly spans of glyphs plus hidden duplicates for screen
+    /// readers, and MathJax 2 keeps the TeX source in `<script type="math/tex">`
+    /// elements that are removed before extraction. When enabled, each formula is
+    /// replaced by its source before scoring: either its MathML `<math>` element, or
+    /// its TeX as `<span class="math inline">\(...\)</span>` (`math display` and
+    /// `\[...\]` for display math). `text_content` shows `<math>` elements by their
+    /// TeX source whenever one is known.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use readabilityrs::{MathNormalization, ReadabilityOptions};
+    ///
+    /// let options = ReadabilityOptions::builder()
+    ///     .math_normalization(MathNormalization::Tex)
+    ///     .build();
+    /// ```
+    ///
+    /// Default: [`MathNormalization::Off`]
+    pub math_normalization: MathNormalization,
+
     /// Remove inline styles from the extracted content.
     ///
     /// When `true`, removes the `style` attribute and other presentational attributes
@@ -187,6 +324,14 @@ impl Default for ReadabilityOptions {
             allowed_video_regex: None,
             link_density_modifier: 0.0,
             remove_title_from_content: false,
+            remove_subtitle_from_content: false,
+            strip_title_branding: false,
+            content_selectors: Vec::new(),
+            content_selector_siblings: false,
+            strip_selectors: Vec::new(),
+            semantic_elements: SemanticElementPolicy::default(),
+            srcset_selection: SrcsetSelection::default(),
+            math_normalization: MathNormalization::default(),
             clean_styles: true,
             clean_whitespace: true,
         }
@@ -228,6 +373,14 @@ pub struct ReadabilityOptionsBuilder {
     allowed_video_regex: Option<Regex>,
     link_density_modifier: Option<f64>,
     remove_title_from_content: Option<bool>,
+    remove_subtitle_from_content: Option<bool>,
+    strip_title_branding: Option<bool>,
+    content_selectors: Option<Vec<String>>,
+    content_selector_siblings: Option<bool>,
+    strip_selectors: Option<Vec<String>>,
+    semantic_elements: Option<SemanticElementPolicy>,
+    srcset_selection: Option<SrcsetSelection>,
+    math_normalization: Option<MathNormalization>,
     clean_styles: Option<bool>,
     clean_whitespace: Option<bool>,
 }
@@ -296,6 +449,79 @@ impl ReadabilityOptionsBuilder {
         self
     }
 
+    /// Remove the subtitle element from the extracted content
+    ///
+    /// When enabled, removes the standfirst/dek element matching the extracted
+    /// subtitle. Useful for rendering the subtitle separately.
+    pub fn remove_subtitle_from_content(mut self, remove: bool) -> Self {
+        self.remove_subtitle_from_content = Some(remove);
+        self
+    }
+
+    /// Strip site branding from the title
+    ///
+    /// When enabled, titles like "Article Name | Site" become "Article Name",
+    /// whatever their source and length.
+    pub fn strip_title_branding(mut self, strip: bool) -> Self {
+        self.strip_title_branding = Some(strip);
+        self
+    }
+
+    /// Add a CSS selector for the article root
+    ///
+    /// Selectors are tried in the order they were added; the first one matching an
+    /// element with text replaces candidate scoring.
+    pub fn content_selector(mut self, selector: impl Into<String>) -> Self {
+        self.content_selectors
+            .get_or_insert_with(Vec::new)
+            .push(selector.into());
+        self
+    }
+
+    /// Set the CSS selectors for the article root, in priority order
+    pub fn content_selectors(mut self, selectors: Vec<String>) -> Self {
+        self.content_selectors = Some(selectors);
+        self
+    }
+
+    /// Add qualifying siblings of the element matched by a content selector
+    pub fn content_selector_siblings(mut self, include: bool) -> Self {
+        self.content_selector_siblings = Some(include);
+        self
+    }
+
+    /// Add a CSS selector for elements that are always removed
+    pub fn strip_selector(mut self, selector: impl Into<String>) -> Self {
+        self.strip_selectors
+            .get_or_insert_with(Vec::new)
+            .push(selector.into());
+        self
+    }
+
+    /// Set the CSS selectors for elements that are always removed
+    pub fn strip_selectors(mut self, selectors: Vec<String>) -> Self {
+        self.strip_selectors = Some(selectors);
+        self
+    }
+
+    /// Set what to do with semantic HTML5 elements in the content
+    pub fn semantic_elements(mut self, policy: SemanticElementPolicy) -> Self {
+        self.semantic_elements = Some(policy);
+        self
+    }
+
+    /// Set how `srcset` and `sizes` on images in the content are handled
+    pub fn srcset_selection(mut self, selection: SrcsetSelection) -> Self {
+        self.srcset_selection = Some(selection);
+        self
+    }
+
+    /// Set how rendered math is normalized to its source form
+    pub fn math_normalization(mut self, normalization: MathNormalization) -> Self {
+        self.math_normalization = Some(normalization);
+        self
+    }
+
     /// Enable or disable inline style cleaning
     ///
     /// When enabled, removes the `style` attribute and other presentational attributes
@@ -336,8 +562,124 @@ impl ReadabilityOptionsBuilder {
             remove_title_from_content: self
                 .remove_title_from_content
                 .unwrap_or(defaults.remove_title_from_content),
+            remove_subtitle_from_content: self
+                .remove_subtitle_from_content
+                .unwrap_or(defaults.remove_subtitle_from_content),
+            strip_title_branding: self
+                .strip_title_branding
+                .unwrap_or(defaults.strip_title_branding),
+            content_selectors: self.content_selectors.unwrap_or(defaults.content_selectors),
+            content_selector_siblings: self
+                .content_selector_siblings
+                .unwrap_or(defaults.content_selector_siblings),
+            strip_selectors: self.strip_selectors.unwrap_or(defaults.strip_selectors),
+            semantic_elements: self.semantic_elements.unwrap_or(defaults.semantic_elements),
+            srcset_selection: self.srcset_selection.unwrap_or(defaults.srcset_selection),
+            math_normalization: self
+                .math_normalization
+                .unwrap_or(defaults.math_normalization),
             clean_styles: self.clean_styles.unwrap_or(defaults.clean_styles),
             clean_whitespace: self.clean_whitespace.unwrap_or(defaults.clean_whitespace),
         }
     }
 }
+
+/// What to do with an element in the extracted content, see [`SemanticElementPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ElementPolicy {
+    /// Keep the element and its content.
+    Keep,
+    /// Keep the content but remove the element's own tag.
+    Unwrap,
+    /// Remove the element and its content.
+    Drop,
+}
+
+/// Per-element policies for semantic HTML5 elements in the extracted content.
+///
+/// The defaults keep everything readers usually render, such as figures with their
+/// captions, pull quotes, disclosure widgets, definition lists and dates, and drop
+/// other `<aside>` boxes, which are mostly related links and promotions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SemanticElementPolicy {
+    /// `<figure>` elements.
+    ///
+    /// Default: [`ElementPolicy::Keep`]
+    pub figure: ElementPolicy,
+    /// `<figcaption>` elements.
+    ///
+    /// Default: [`ElementPolicy::Keep`]
+    pub figcaption: ElementPolicy,
+    /// `<details>` elements.
+    ///
+    /// Default: [`ElementPolicy::Keep`]
+    pub details: ElementPolicy,
+    /// `<summary>` elements inside `<details>`.
+    ///
+    /// Default: [`ElementPolicy::Keep`]
+    pub summary: ElementPolicy,
+    /// `<aside>` and `<blockquote>` elements whose class or id marks them as pull
+    /// quotes (`pullquote`, `pull-quote`, `pullout`).
+    ///
+    /// Default: [`ElementPolicy::Keep`]
+    pub pullquote: ElementPolicy,
+    /// Any other `<aside>` element.
+    ///
+    /// Default: [`ElementPolicy::Drop`]
+    pub aside: ElementPolicy,
+    /// `<dl>` definition lists.
+    ///
+    /// Default: [`ElementPolicy::Keep`]
+    pub dl: ElementPolicy,
+    /// `<time>` elements.
+    ///
+    /// Default: [`ElementPolicy::Keep`]
+    pub time: ElementPolicy,
+}
+
+impl Default for SemanticElementPolicy {
+    fn default() -> Self {
+        Self {
+            figure: ElementPolicy::Keep,
+            figcaption: ElementPolicy::Keep,
+            details: ElementPolicy::Keep,
+            summary: ElementPolicy::Keep,
+            pullquote: ElementPolicy::Keep,
+            aside: ElementPolicy::Drop,
+            dl: ElementPolicy::Keep,
+            time: ElementPolicy::Keep,
+        }
+    }
+}
+
+/// How `srcset` and `sizes` on images in the extracted content are handled.
+///
+/// Collapsing replaces `srcset` and `sizes` with a single `src` and turns each
+/// `<picture>` into its `<img>`. For a `<picture>`, the first `<source>` whose `media`
+/// matches and whose `type` is a common web image format is used, as a browser would.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SrcsetSelection {
+    /// Keep `srcset` and `sizes` as published.
+    #[default]
+    Keep,
+    /// Collapse to the candidate a browser would load in a viewport this many CSS
+    /// pixels wide, at a pixel density of 1.
+    Width(u32),
+    /// Collapse to the candidate closest to (and not below) this pixel density, e.g.
+    /// `2.0` for high-density screens.
+    Density(f32),
+}
+
+/// Source form that rendered math is normalized to, see
+/// [`ReadabilityOptions::math_normalization`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MathNormalization {
+    /// Leave math markup as published.
+    #[default]
+    Off,
+    /// Keep the MathML `<math>` element, with its TeX source in `alttext` when known.
+    /// Formulas only available as TeX are kept as TeX.
+    MathMl,
+    /// Keep the TeX source. Formulas only available as MathML are kept as MathML.
+    Tex,
+}
@@ -0,0 +1,360 @@
+//! "Next page" detection for articles split over several pages, and merging of
+//! the pages once they have been fetched and parsed.
+
+use crate::article::Article;
+use crate::post_processor;
+use crate::utils;
+use kuchikikiki::traits::*;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use scraper::{ElementRef, Html, Selector};
+use std::collections::HashSet;
+
+static PAGINATION_CONTAINER_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)pagination|pager|paging|page-numbers|pagenav|page-nav|page-links|article-pages",
+    )
+    .unwrap()
+});
+static NEXT_CLASS_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)(^|[\s_-])next([\s_-]|$)|nextpage|next-page|pagination-next|page-next")
+        .unwrap()
+});
+static NEXT_TEXT_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)^(next( page)?|continue( reading)?|weiter|nächste( seite)?|suivant(e)?|page suivante|siguiente|página siguiente|próxima( página)?|successiva|pagina successiva|volgende|далее|следующая|次へ|次のページ|下一页|›|»|→|>)?\s*[›»→>]*$",
+    )
+    .unwrap()
+});
+/// Link texts that are unambiguous even outside a pagination container
+static NEXT_PAGE_TEXT_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)^(next page|nächste seite|page suivante|página siguiente|próxima página|pagina successiva|volgende pagina|следующая страница|次のページ|下一页|continue reading on (the )?next page)\s*[›»→>]*$",
+    )
+    .unwrap()
+});
+static PREV_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)prev|previous|zurück|précédent|anterior|vorige|назад|«|‹|←").unwrap()
+});
+static PAGE_NUMBER_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)(?:[?&;](?:page|p|pg|paged|pagina|seite|pagenum)=|/(?:page|seite|pagina)/)(\d{1,4})(?:[&#/]|$)",
+    )
+    .unwrap()
+});
+
+/// Find the link to the next page of a paginated article
+///
+/// Looks for `rel="next"` first, then for "Next" links and classes in pagination
+/// blocks, and finally for a link numbered one more than `current_url`'s page
+/// (`?page=2`, `/page/2/`). The returned link is as written in the page; resolve it
+/// against the document base URL before use.
+pub fn extract_next_page_url(document: &Html, current_url: Option<&str>) -> Option<String> {
+    static REL_NEXT_SELECTOR: Lazy<Selector> =
+        Lazy::new(|| Selector::parse("link[rel~='next'][href], a[rel~='next'][href]").unwrap());
+    static LINK_SELECTOR: Lazy<Selector> = Lazy::new(|| Selector::parse("a[href]").unwrap());
+
+    let usable = |href: &str| {
+        let href = href.trim();
+        let lower = href.to_ascii_lowercase();
+        !href.is_empty()
+            && !href.starts_with('#')
+            && !lower.starts_with("javascript:")
+            && !lower.starts_with("mailto:")
+            && current_url.is_none_or(|current| !same_page(href, current))
+    };
+
+    if let Some(href) = document
+        .select(&REL_NEXT_SELECTOR)
+        .filter_map(|element| element.value().attr("href"))
+        .find(|href| usable(href))
+    {
+        return Some(href.trim().to_string());
+    }
+
+    let links: Vec<(ElementRef, &str)> = document
+        .select(&LINK_SELECTOR)
+        .filter_map(|link| Some((link, link.value().attr("href")?)))
+        .filter(|(_, href)| usable(href))
+        .collect();
+
+    let next_link = links.iter().find(|(link, _)| {
+        let text = utils::normalize_whitespace(link.text().collect::<String>().trim());
+        let label = link.value().attr("aria-label").unwrap_or_default();
+        if PREV_REGEX.is_match(&text) || PREV_REGEX.is_match(label) {
+            return false;
+        }
+        if NEXT_PAGE_TEXT_REGEX.is_match(&text) || NEXT_PAGE_TEXT_REGEX.is_match(label) {
+            return true;
+        }
+        in_pagination_container(*link)
+            && (has_next_class(*link)
+                || (!text.is_empty() && NEXT_TEXT_REGEX.is_match(&text))
+                || (!label.is_empty() && NEXT_TEXT_REGEX.is_match(label)))
+    });
+    if let Some((_, href)) = next_link {
+        return Some(href.trim().to_string());
+    }
+
+    let next_number = current_url.and_then(page_number).unwrap_or(1) + 1;
+    links
+        .iter()
+        .find(|(link, href)| {
+            link.text().collect::<String>().trim() == next_number.to_string()
+                && (in_pagination_container(*link) || page_number(href) == Some(next_number))
+        })
+        .map(|(_, href)| href.trim().to_string())
+}
+
+/// Resolve a next-page link, dropping it when it leaves the site or points back
+/// to the current page
+pub fn resolve_next_page_url(
+    href: &str,
+    base_url: &str,
+    current_url: Option<&str>,
+) -> Option<String> {
+    let resolved = url::Url::parse(&utils::resolve_url(href, base_url)?).ok()?;
+    let base = url::Url::parse(base_url).ok()?;
+    if resolved.host_str() != base.host_str()
+        || same_page(resolved.as_str(), base_url)
+        || current_url.is_some_and(|current| same_page(resolved.as_str(), current))
+    {
+        return None;
+    }
+    Some(resolved.to_string())
+}
+
+/// Merge the articles parsed from consecutive pages into one
+///
+/// The first page provides the metadata; later pages only contribute content,
+/// images and missing fields. Headings, standfirsts and bylines repeated at the
+/// top of later pages are dropped. `next_page_url` is taken from the last page.
+/// Returns `None` for an empty list.
+///
+/// ```rust
+/// use readabilityrs::{merge_pages, Readability};
+///
+/// let page = |n: u32| {
+///     format!(
+///         r#"<html><head><title>Bridge to be rebuilt</title></head><body><article>
+///         <h1>Bridge to be rebuilt</h1>
+///         <p>Part {n} of the story about the old bridge, which the council voted to replace after years of debate.</p>
+///         <p>Work on section {n} is expected to begin next spring and last for about eighteen months in total.</p>
+///         </article></body></html>"#
+///     )
+/// };
+/// let mut pages = Vec::new();
+/// for n in 1..=2 {
+///     pages.extend(Readability::new(&page(n), None, None)?.parse());
+/// }
+///
+/// let article = merge_pages(pages).unwrap();
+/// let text = article.text_content.unwrap();
+/// assert!(text.contains("Part 1") && text.contains("Part 2"));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn merge_pages(pages: Vec<Article>) -> Option<Article> {
+    let mut pages = pages.into_iter();
+    let mut merged = pages.next()?;
+
+    let mut content = merged.content.take().unwrap_or_default();
+    let mut raw_content = merged.raw_content.take();
+    let repeated = leading_block_texts(&content, &merged);
+
+    for page in pages {
+        if let Some(mut page_content) = page.content {
+            if let Some(title) = merged.title.as_deref() {
+                page_content = post_processor::remove_title_from_content(&page_content, title);
+            }
+            if let Some(subtitle) = merged.subtitle.as_deref() {
+                page_content =
+                    post_processor::remove_subtitle_from_content(&page_content, subtitle);
+            }
+            content.push('\n');
+            content.push_str(&drop_repeated_blocks(&page_content, &repeated));
+        }
+        if let Some(page_raw) = page.raw_content {
+            let raw = raw_content.get_or_insert_with(String::new);
+            raw.push('\n');
+            raw.push_str(&page_raw);
+        }
+
+        for image in page.images {
+            if !merged.images.iter().any(|known| known.url == image.url) {
+                merged.images.push(image);
+            }
+        }
+        merged.byline = merged.byline.or(page.byline);
+        merged.published_time = merged.published_time.or(page.published_time);
+        merged.image = merged.image.or(page.image);
+        merged.next_page_url = page.next_page_url;
+    }
+
+    let text_content = crate::dom_utils::content_text(&content);
+    merged.length = text_content.len();
+    merged.text_content = Some(text_content);
+    merged.content = Some(content);
+    merged.raw_content = raw_content;
+    Some(merged)
+}
+
+fn in_pagination_container(link: ElementRef) -> bool {
+    std::iter::once(link)
+        .chain(link.ancestors().filter_map(ElementRef::wrap))
+        .take(5)
+        .any(|element| {
+            element.value().name() == "nav"
+                && element
+                    .value()
+                    .attr("aria-label")
+                    .is_some_and(|label| PAGINATION_CONTAINER_REGEX.is_match(label))
+                || element
+                    .value()
+                    .attr("class")
+                    .into_iter()
+                    .chain(element.value().attr("id"))
+                    .any(|value| PAGINATION_CONTAINER_REGEX.is_match(value))
+        })
+}
+
+fn has_next_class(link: ElementRef) -> bool {
+    std::iter::once(link)
+        .chain(link.parent().and_then(ElementRef::wrap))
+        .any(|element| {
+            element
+                .value()
+                .attr("class")
+                .into_iter()
+                .chain(element.value().attr("id"))
+                .any(|value| NEXT_CLASS_REGEX.is_match(value))
+        })
+}
+
+/// The page number in a `?page=3` or `/page/3/` URL
+fn page_number(url: &str) -> Option<u32> {
+    PAGE_NUMBER_REGEX.captures(url)?[1].parse().ok()
+}
+
+/// Whether two links point at the same page, ignoring fragments and a trailing slash
+fn same_page(a: &str, b: &str) -> bool {
+    let strip = |url: &str| {
+        let url = url.split('#').next().unwrap_or_default();
+        url.trim_end_matches('/').to_string()
+    };
+    strip(a) == strip(b)
+}
+
+/// Texts of the headings and short blocks that open the first page, plus its byline
+fn leading_block_texts(content: &str, first: &Article) -> HashSet<String> {
+    static BLOCK_SELECTOR: Lazy<Selector> =
+        Lazy::new(|| Selector::parse("h1, h2, h3, h4, h5, h6, p, header, figcaption").unwrap());
+
+    let mut texts: HashSet<String> = Html::parse_fragment(content)
+        .select(&BLOCK_SELECTOR)
+        .take(8)
+        .map(|block| normalize(&block.text().collect::<String>()))
+        .filter(|text| !text.is_empty())
+        .collect();
+    texts.extend(
+        [&first.title, &first.subtitle, &first.byline]
+            .into_iter()
+            .flatten()
+            .map(|text| normalize(text)),
+    );
+    texts
+}
+
+/// Drop blocks at the top of a later page that repeat the first page's header
+///
+/// Stops at the first block with new text, so repeated text further down the
+/// page is kept.
+fn drop_repeated_blocks(html: &str, repeated: &HashSet<String>) -> String {
+    let document = kuchikikiki::parse_html().one(html);
+    let Ok(blocks) = document.select("h1, h2, h3, h4, h5, h6, p, header, figcaption") else {
+        return html.to_string();
+    };
+    let blocks: Vec<_> = blocks.map(|block| block.as_node().clone()).collect();
+
+    for block in blocks {
+        let text = normalize(&block.text_contents());
+        if text.is_empty() {
+            continue;
+        }
+        if !repeated.contains(&text) {
+            break;
+        }
+        block.detach();
+    }
+
+    let Ok(body) = document.select_first("body") else {
+        return html.to_string();
+    };
+    let mut buffer = Vec::new();
+    for child in body.as_node().children() {
+        if child.serialize(&mut buffer).is_err() {
+            return html.to_string();
+        }
+    }
+    String::from_utf8(buffer).unwrap_or_else(|_| html.to_string())
+}
+
+fn normalize(text: &str) -> String {
+    utils::normalize_whitespace(text.trim()).to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn next_page(html: &str, current_url: Option<&str>) -> Option<String> {
+        extract_next_page_url(&Html::parse_document(html), current_url)
+    }
+
+    #[test]
+    fn test_next_page_from_rel_and_pagination() {
+        let html =
+            r#"<html><head><link rel="next" href="/story?page=2"></head><body></body></html>"#;
+        assert_eq!(next_page(html, None).as_deref(), Some("/story?page=2"));
+
+        let html = r#"<html><body><article><p>Text</p></article>
+            <div class="pagination"><a href="/story">1</a><a href="/story/page/2/">Next ›</a></div>
+            </body></html>"#;
+        assert_eq!(next_page(html, None).as_deref(), Some("/story/page/2/"));
+
+        let html = r#"<html><body><article><p>Text</p>
+            <a href="/story?page=1">Previous</a> <a href="/story?page=3">3</a>
+            </article></body></html>"#;
+        assert_eq!(
+            next_page(html, Some("https://example.com/story?page=2")).as_deref(),
+            Some("/story?page=3")
+        );
+        assert_eq!(next_page(html, None), None);
+    }
+
+    #[test]
+    fn test_resolve_next_page_url() {
+        let base = "https://example.com/news/story";
+        assert_eq!(
+            resolve_next_page_url("story?page=2", base, None).as_deref(),
+            Some("https://example.com/news/story?page=2")
+        );
+        assert_eq!(
+            resolve_next_page_url("https://other.com/x", base, None),
+            None
+        );
+        assert_eq!(resolve_next_page_url("/news/story/", base, None), None);
+    }
+
+    #[test]
+    fn test_drop_repeated_blocks() {
+        let repeated: HashSet<String> = ["river floods", "by jane smith"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let html = "<h2>River floods</h2><p>By Jane Smith</p><p>New text.</p><p>By Jane Smith</p>";
+        assert_eq!(
+            drop_repeated_blocks(html, &repeated),
+            "<p>New text.</p><p>By Jane Smith</p>"
+        );
+    }
+}
@@ -3,6 +3,7 @@
 //! This module implements Mozilla's _prepArticle pipeline, which cleans
 //! the extracted article content by removing unwanted elements.
 
+use crate::options::{ElementPolicy, SemanticElementPolicy};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use scraper::{Html, Selector};
@@ -33,22 +34,45 @@ fn unwrap_nav_wrappers(html: &str) -> String {
 /// # Returns
 /// The HTML with the matching title element removed, or the original HTML if no match found
 pub fn remove_title_from_content(html: &str, title: &str) -> String {
+    static HEADING_SELECTOR: Lazy<Selector> = Lazy::new(|| Selector::parse("h1, h2").unwrap());
+
+    remove_matching_element(html, title, &HEADING_SELECTOR)
+}
+
+/// Remove the subtitle (standfirst/dek) element from the article content.
+///
+/// Finds the first heading or paragraph whose text matches the given subtitle
+/// and removes it, cleaning up leftovers the same way as
+/// [`remove_title_from_content`]. Wrappers are not matched themselves, as they
+/// often hold a byline or date next to the dek.
+///
+/// # Arguments
+/// * `html` - The article HTML content
+/// * `subtitle` - The extracted article subtitle to match against
+///
+/// # Returns
+/// The HTML with the matching subtitle element removed, or the original HTML if no match found
+pub fn remove_subtitle_from_content(html: &str, subtitle: &str) -> String {
+    static DEK_SELECTOR: Lazy<Selector> = Lazy::new(|| Selector::parse("h2, h3, h4, p").unwrap());
+
+    remove_matching_element(html, subtitle, &DEK_SELECTOR)
+}
+
+/// Remove the first element matched by `selector` whose text matches `text`
+fn remove_matching_element(html: &str, text: &str, selector: &Selector) -> String {
     let doc = Html::parse_fragment(html);
 
-    // Normalize the title for comparison
-    let normalized_title = normalize_text(title);
+    // Normalize the text for comparison
+    let normalized_title = normalize_text(text);
     if normalized_title.is_empty() {
         return html.to_string();
     }
 
-    // Try to find h1 or h2 elements that match the title
-    let selector = Selector::parse("h1, h2").unwrap();
-
-    for element in doc.select(&selector) {
+    for element in doc.select(selector) {
         let element_text: String = element.text().collect();
         let normalized_element_text = normalize_text(&element_text);
 
-        // Check if the heading text matches the title (exact or near match)
+        // Check if the element text matches (exact or near match)
         if titles_match(&normalized_title, &normalized_element_text) {
             let tag_name = element.value().name();
 
@@ -182,7 +206,13 @@ fn titles_match(title1: &str, title2: &str) -> bool {
 /// * `html` - The raw extracted article HTML
 /// * `clean_styles_opt` - Whether to remove inline styles (implements Mozilla's _cleanStyles)
 /// * `clean_whitespace_opt` - Whether to normalize whitespace and remove empty paragraphs
-pub fn prep_article(html: &str, clean_styles_opt: bool, clean_whitespace_opt: bool) -> String {
+/// * `semantic` - What to do with figures, asides, pull quotes and other semantic elements
+pub fn prep_article(
+    html: &str,
+    clean_styles_opt: bool,
+    clean_whitespace_opt: bool,
+    semantic: &SemanticElementPolicy,
+) -> String {
     let mut html = html.to_string();
 
     // Unwrap nav wrappers before removing elements
@@ -194,8 +224,11 @@ pub fn prep_article(html: &str, clean_styles_opt: bool, clean_whitespace_opt: bo
         html = clean_styles(&html);
     }
 
-    // Step 2: Remove unwanted elements
+    // Step 2: Remove unwanted elements and dropped asides, then apply the rest of
+    // the semantic element policy
     html = remove_unwanted_elements(&html);
+    html = remove_asides(&html, semantic);
+    html = crate::cleaner::apply_semantic_policy(&html, semantic);
 
     // Step 3: Remove share buttons and social widgets
     html = remove_share_elements(&html);
@@ -248,22 +281,39 @@ fn clean_styles(html: &str) -> String {
 /// This function:
 /// - Removes excessive blank lines (more than 2 consecutive newlines)
 /// - Collapses multiple spaces into single spaces
+///
+/// `<pre>`, `<code>` and `<textarea>` blocks are left untouched so code keeps its
+/// indentation.
 fn normalize_whitespace(html: &str) -> String {
     // Multiple consecutive newlines -> 2 newlines (fast single pass)
-    static MULTI_NEWLINE: Lazy<Regex> =
-        Lazy::new(|| Regex::new(r"\n{3,}").unwrap());
+    static MULTI_NEWLINE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\n{3,}").unwrap());
     // Multiple spaces -> single space
-    static MULTI_SPACE: Lazy<Regex> =
-        Lazy::new(|| Regex::new(r" {2,}").unwrap());
+    static MULTI_SPACE: Lazy<Regex> = Lazy::new(|| Regex::new(r" {2,}").unwrap());
+    // Whitespace-sensitive blocks
+    static PRESERVED_BLOCK: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"(?is)<pre\b.*?</pre\s*>|<code\b.*?</code\s*>|<textarea\b.*?</textarea\s*>")
+            .unwrap()
+    });
 
-    let result = MULTI_NEWLINE.replace_all(html, "\n\n");
-    let result = MULTI_SPACE.replace_all(&result, " ");
-    result.to_string()
+    let normalize = |text: &str| {
+        let text = MULTI_NEWLINE.replace_all(text, "\n\n");
+        MULTI_SPACE.replace_all(&text, " ").into_owned()
+    };
+
+    let mut result = String::with_capacity(html.len());
+    let mut last = 0;
+    for block in PRESERVED_BLOCK.find_iter(html) {
+        result.push_str(&normalize(&html[last..block.start()]));
+        result.push_str(block.as_str());
+        last = block.end();
+    }
+    result.push_str(&normalize(&html[last..]));
+    result
 }
 
 /// Remove unwanted elements that are never part of article content
 ///
-/// Removes: forms, fieldsets, footer, aside, object, embed, iframe,
+/// Removes: forms, fieldsets, footer, object, embed, iframe,
 /// input, textarea, select, button
 fn remove_unwanted_elements(html: &str) -> String {
     let mut result = html.to_string();
@@ -271,7 +321,6 @@ fn remove_unwanted_elements(html: &str) -> String {
         ("form", r"(?is)<form\b[^>]*?>.*?</form>"),
         ("fieldset", r"(?is)<fieldset\b[^>]*?>.*?</fieldset>"),
         ("footer", r"(?is)<footer\b[^>]*?>.*?</footer>"),
-        ("aside", r"(?is)<aside\b[^>]*?>.*?</aside>"),
         ("object", r"(?is)<object\b[^>]*?>.*?</object>"),
         (
             "embed",
@@ -296,6 +345,35 @@ fn remove_unwanted_elements(html: &str) -> String {
     result
 }
 
+/// Remove `<aside>` elements whose policy is [`ElementPolicy::Drop`]
+///
+/// Pull quotes follow [`SemanticElementPolicy::pullquote`], other asides
+/// [`SemanticElementPolicy::aside`]. Kept and unwrapped asides are left for
+/// [`crate::cleaner::apply_semantic_policy`].
+fn remove_asides(html: &str, semantic: &SemanticElementPolicy) -> String {
+    static ASIDE_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?is)<aside\b([^>]*?)>.*?</aside>").unwrap());
+
+    if semantic.aside != ElementPolicy::Drop && semantic.pullquote != ElementPolicy::Drop {
+        return html.to_string();
+    }
+
+    ASIDE_REGEX
+        .replace_all(html, |caps: &regex::Captures| {
+            let policy = if crate::cleaner::is_pullquote(&caps[1]) {
+                semantic.pullquote
+            } else {
+                semantic.aside
+            };
+            if policy == ElementPolicy::Drop {
+                String::new()
+            } else {
+                caps[0].to_string()
+            }
+        })
+        .to_string()
+}
+
 /// Remove share buttons and social widgets
 ///
 /// Removes elements with "share" or "social" in their class/id
@@ -485,7 +563,7 @@ mod tests {
             </article>
         "#;
 
-        let cleaned = prep_article(html, true, true);
+        let cleaned = prep_article(html, true, true, &SemanticElementPolicy::default());
 
         assert!(cleaned.contains("<h1>Article Title</h1>"));
         assert!(cleaned.contains("<p>First paragraph</p>"));
@@ -495,6 +573,37 @@ mod tests {
         assert!(!cleaned.contains("<p></p>"));
     }
 
+    #[test]
+    fn test_normalize_whitespace_preserves_code() {
+        let html = "<p>Some   text</p>\n\n\n\n\
+            <pre><code>fn main() {\n    let x  = 1;\n\n\n\n}</code></pre>\
+            <p>Inline <code>a  +  b</code> and   more</p>";
+
+        let normalized = normalize_whitespace(html);
+        assert!(normalized.starts_with("<p>Some text</p>\n\n<pre>"));
+        assert!(normalized.contains("<code>fn main() {\n    let x  = 1;\n\n\n\n}</code>"));
+        assert!(normalized.contains("<code>a  +  b</code> and more"));
+    }
+
+    #[test]
+    fn test_prep_article_aside_policy() {
+        let html = r#"<p>Story</p><aside class="pull-quote">Quoted line</aside>
+            <aside class="related">More stories</aside>"#;
+
+        let cleaned = prep_article(html, true, true, &SemanticElementPolicy::default());
+        assert!(cleaned.contains("Quoted line"));
+        assert!(!cleaned.contains("More stories"));
+
+        let policy = SemanticElementPolicy {
+            aside: ElementPolicy::Keep,
+            pullquote: ElementPolicy::Drop,
+            ..Default::default()
+        };
+        let cleaned = prep_article(html, true, true, &policy);
+        assert!(!cleaned.contains("Quoted line"));
+        assert!(cleaned.contains("More stories"));
+    }
+
     #[test]
     fn test_remove_title_from_content_h1() {
         let html = r#"
@@ -642,4 +751,36 @@ mod tests {
         assert!(cleaned.contains("By Author"));
         assert!(cleaned.contains("<p>Content</p>"));
     }
+
+    #[test]
+    fn test_remove_subtitle_from_content() {
+        let html = r#"<article>
+  <div class="standfirst"><p>The council voted on Tuesday to rebuild the old bridge.</p></div>
+  <p>The council met on Tuesday.</p>
+</article>"#;
+
+        let cleaned = remove_subtitle_from_content(
+            html,
+            "The council voted on Tuesday to rebuild the old bridge.",
+        );
+
+        assert!(!cleaned.contains("rebuild the old bridge"));
+        assert!(!cleaned.contains("standfirst"));
+        assert!(cleaned.contains("<p>The council met on Tuesday.</p>"));
+    }
+
+    #[test]
+    fn test_remove_subtitle_keeps_byline_in_wrapper() {
+        let html = r#"<article>
+  <div class="standfirst"><p>The council voted on Tuesday to rebuild the old bridge over the river.</p><span>By Jane Smith</span></div>
+  <p>The council met on Tuesday.</p>
+</article>"#;
+
+        let cleaned = remove_subtitle_from_content(
+            html,
+            "The council voted on Tuesday to rebuild the old bridge over the river.",
+        );
+        assert!(!cleaned.contains("rebuild the old bridge"));
+        assert!(cleaned.contains(r#"<div class="standfirst"><span>By Jane Smith</span></div>"#));
+    }
 }
@@ -27,14 +27,15 @@
 //! ```
 
 use crate::{
-    article::Article,
+    article::{Article, ArticleAccess, ArticleCandidate, ArticleMetadata, ExtractionFlags},
     cleaner,
-    content_extractor::grab_article,
+    constants::ParseFlags,
+    content_extractor::{grab_article, grab_candidates},
     dom_utils,
     error::{ReadabilityError, Result},
-    metadata::{get_article_metadata, get_json_ld, Metadata},
+    metadata::{get_article_metadata, get_json_ld, resolve_metadata_urls, select_title, Metadata},
     options::ReadabilityOptions,
-    utils,
+    pagination, utils,
 };
 use scraper::{ElementRef, Html, Selector};
 
@@ -143,6 +144,14 @@ impl Readability {
             .transpose()?;
 
         let options = options.unwrap_or_default();
+        for selector in options
+            .content_selectors
+            .iter()
+            .chain(&options.strip_selectors)
+        {
+            Selector::parse(selector)
+                .map_err(|_| ReadabilityError::InvalidSelector(selector.clone()))?;
+        }
 
         Ok(Self {
             document,
@@ -158,50 +167,48 @@ impl Readability {
     /// # Returns
     /// `Option<Article>` - Some(article) if successful, None if no article found
     pub fn parse(mut self) -> Option<Article> {
-        let json_ld = if !self.options.disable_json_ld {
-            get_json_ld(&self.document)
-        } else {
-            Metadata::default()
-        };
-
-        self.metadata = get_article_metadata(&self.document, json_ld);
+        let (metadata, document_base_url) = self.extract_metadata();
+        self.metadata = metadata;
 
-        let preprocessed_html = cleaner::prep_document(&self.html);
+        let preprocessed_html = cleaner::prep_document(
+            &self.html,
+            &self.options.strip_selectors,
+            self.options.math_normalization,
+        );
         let preprocessed_doc = Html::parse_document(&preprocessed_html);
 
         match grab_article(&preprocessed_doc, &self.options) {
-            Ok(Some(content_html)) => {
-                let cleaned_wrapper_html =
-                    cleaner::clean_article_content_light(&content_html, self.base_url.as_deref())
-                        .unwrap_or_else(|_| content_html.clone());
-
-                let mut prepped_html = crate::post_processor::prep_article(
-                    &cleaned_wrapper_html,
-                    self.options.clean_styles,
-                    self.options.clean_whitespace,
+            Ok(Some(extracted)) => {
+                select_title(
+                    &mut self.metadata,
+                    extracted.heading.as_deref(),
+                    self.options.strip_title_branding,
                 );
-
-                // Remove title from content if the option is enabled
-                if self.options.remove_title_from_content {
-                    if let Some(ref title) = self.metadata.title {
-                        prepped_html =
-                            crate::post_processor::remove_title_from_content(&prepped_html, title);
-                    }
-                }
-                let cleaned_html =
-                    match cleaner::clean_article_content(&prepped_html, self.base_url.as_deref()) {
-                        Ok(html) => html,
-                        Err(e) => {
-                            if self.options.debug {
-                                eprintln!("Error cleaning content: {e}");
-                            }
-                            prepped_html
-                        }
-                    };
+                let content_html = extracted.content;
+                let cleaned_html = self.clean_content(&content_html, &self.metadata);
 
                 let text_content = self.get_text_content(&cleaned_html);
                 let length = text_content.len();
 
+                // Text direction: closest `dir` around the extracted content, then the
+                // document, then the first strong character of the text when it is
+                // right-to-left (left-to-right is what readers assume without a `dir`)
+                let text_dir = dom_utils::detect_text_direction(&text_content);
+                let dir = extracted
+                    .dir
+                    .clone()
+                    .or_else(|| dom_utils::get_article_direction(&self.document))
+                    .or_else(|| text_dir.filter(|dir| *dir == "rtl").map(str::to_string));
+
+                // Right-to-left blocks in a left-to-right article get their own `dir`
+                let block_reference_dir = match dir.as_deref() {
+                    Some("auto") => text_dir.unwrap_or("ltr"),
+                    Some(dir) => dir,
+                    None => "ltr",
+                };
+                let cleaned_html =
+                    cleaner::mark_block_directions(&cleaned_html, block_reference_dir);
+
                 // Generate excerpt from content if not in metadata
                 // Try first paragraph of extracted content, then fall back to text
                 let excerpt = self.metadata.excerpt.clone().or_else(|| {
@@ -209,11 +216,16 @@ impl Readability {
                         .or_else(|| self.generate_excerpt_from_text(&text_content))
                 });
 
-                // Extract text direction from document
-                let dir = crate::dom_utils::get_article_direction(&self.document);
+                let mut images = self.metadata.images;
+                images.extend(crate::images::extract_content_images(&cleaned_html));
+                let images = crate::images::finalize_images(images, document_base_url.as_deref());
+
+                let detected_lang = crate::language::detect_language(&text_content);
 
                 Some(Article {
                     title: self.metadata.title,
+                    title_source: self.metadata.title_source,
+                    subtitle: self.metadata.subtitle,
                     content: Some(cleaned_html),
                     raw_content: Some(content_html),
                     text_content: Some(text_content),
@@ -221,10 +233,30 @@ impl Readability {
                     excerpt,
                     image: self.metadata.image,
                     byline: self.metadata.byline,
+                    byline_source: self.metadata.byline_source,
+                    byline_confidence: self.metadata.byline_confidence,
                     dir,
                     site_name: self.metadata.site_name,
+                    publisher: self.metadata.publisher,
                     lang: self.metadata.lang,
                     published_time: self.metadata.published_time,
+                    detected_lang,
+                    access: self
+                        .metadata
+                        .access
+                        .unwrap_or(if extracted.paywall_detected {
+                            ArticleAccess::SubscriberOnly
+                        } else {
+                            ArticleAccess::Unknown
+                        }),
+                    images,
+                    canonical_url: self.metadata.canonical_url,
+                    author_urls: self.metadata.author_urls,
+                    favicon: crate::images::pick_favicon(&self.metadata.site_icons),
+                    site_icons: self.metadata.site_icons,
+                    discussion: self.metadata.discussion,
+                    next_page_url: self.metadata.next_page_url,
+                    confidence: extracted.confidence,
                 })
             }
             Ok(None) => None,
@@ -237,10 +269,235 @@ impl Readability {
         }
     }
 
+    /// Extract up to `n` alternative article bodies with their scores
+    ///
+    /// Runs every scoring pass instead of stopping at the first one that finds
+    /// enough text, and keeps the best candidate of each pass plus the next
+    /// highest-scoring elements, dropping extractions already found. Content
+    /// selectors are not applied, so the first candidate is the body
+    /// [`parse`](Readability::parse) returns only when none of
+    /// [`content_selectors`](ReadabilityOptions::content_selectors) matches.
+    /// Useful for letting an editor pick the right body when the top pick is
+    /// wrong.
+    ///
+    /// ```rust
+    /// use readabilityrs::Readability;
+    ///
+    /// let paragraph = "<p>The council approved the new bridge on Monday, ending years of debate.</p>";
+    /// let html = format!("<html><body><article>{}</article></body></html>", paragraph.repeat(10));
+    ///
+    /// let readability = Readability::new(&html, None, None)?;
+    /// let candidates = readability.parse_candidates(3);
+    /// assert!(!candidates.is_empty());
+    /// assert_eq!(candidates[0].relative_score, 1.0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_candidates(&self, n: usize) -> Vec<ArticleCandidate> {
+        let (mut metadata, _) = self.extract_metadata();
+        select_title(&mut metadata, None, self.options.strip_title_branding);
+
+        let preprocessed_html = cleaner::prep_document(
+            &self.html,
+            &self.options.strip_selectors,
+            self.options.math_normalization,
+        );
+        let preprocessed_doc = Html::parse_document(&preprocessed_html);
+        let extractions = match grab_candidates(&preprocessed_doc, &self.options, n) {
+            Ok(extractions) => extractions,
+            Err(e) => {
+                if self.options.debug {
+                    eprintln!("Error grabbing candidates: {e}");
+                }
+                return Vec::new();
+            }
+        };
+
+        let top_score = extractions
+            .iter()
+            .map(|extraction| extraction.score)
+            .fold(0.0, f64::max);
+        extractions
+            .into_iter()
+            .map(|extraction| {
+                let content = self.clean_content(&extraction.article.content, &metadata);
+                let text_content = self.get_text_content(&content);
+                let length = text_content.len();
+
+                let score_ratio = if top_score > 0.0 {
+                    (extraction.score / top_score).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                let completeness = if self.options.char_threshold > 0 {
+                    (length as f64 / self.options.char_threshold as f64).min(1.0)
+                } else {
+                    1.0
+                };
+
+                ArticleCandidate {
+                    content,
+                    text_content,
+                    length,
+                    score: extraction.score,
+                    relative_score: score_ratio * completeness,
+                    flags: ExtractionFlags {
+                        strip_unlikelys: extraction.flags.contains(ParseFlags::STRIP_UNLIKELYS),
+                        weight_classes: extraction.flags.contains(ParseFlags::WEIGHT_CLASSES),
+                        clean_conditionally: extraction
+                            .flags
+                            .contains(ParseFlags::CLEAN_CONDITIONALLY),
+                    },
+                }
+            })
+            .collect()
+    }
+
+    /// Extract only the document metadata, skipping content extraction
+    ///
+    /// Runs the JSON-LD, meta tag, microdata and document heuristics used by
+    /// [`parse`](Readability::parse), but none of the preprocessing, scoring and
+    /// cleaning. Much cheaper than a full parse when only the title, byline, image
+    /// and dates are needed, e.g. for link previews.
+    ///
+    /// ```rust
+    /// use readabilityrs::Readability;
+    ///
+    /// let html = r#"<html><head>
+    ///     <title>Bridge to be rebuilt</title>
+    ///     <meta name="author" content="Jane Smith">
+    ///     <meta property="og:image" content="/lead.jpg">
+    /// </head><body></body></html>"#;
+    ///
+    /// let readability = Readability::new(html, Some("https://example.com/news/"), None)?;
+    /// let metadata = readability.metadata();
+    /// assert_eq!(metadata.title.as_deref(), Some("Bridge to be rebuilt"));
+    /// assert_eq!(metadata.byline.as_deref(), Some("Jane Smith"));
+    /// assert_eq!(metadata.image.as_deref(), Some("https://example.com/lead.jpg"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn metadata(&self) -> ArticleMetadata {
+        let (mut metadata, document_base_url) = self.extract_metadata();
+        select_title(&mut metadata, None, self.options.strip_title_branding);
+        let images = crate::images::finalize_images(metadata.images, document_base_url.as_deref());
+
+        ArticleMetadata {
+            title: metadata.title,
+            title_source: metadata.title_source,
+            subtitle: metadata.subtitle,
+            excerpt: metadata.excerpt,
+            byline: metadata.byline,
+            byline_source: metadata.byline_source,
+            byline_confidence: metadata.byline_confidence,
+            image: metadata.image,
+            dir: dom_utils::get_article_direction(&self.document),
+            site_name: metadata.site_name,
+            publisher: metadata.publisher,
+            lang: metadata.lang,
+            published_time: metadata.published_time,
+            access: metadata.access.unwrap_or_default(),
+            images,
+            canonical_url: metadata.canonical_url,
+            author_urls: metadata.author_urls,
+            favicon: crate::images::pick_favicon(&metadata.site_icons),
+            site_icons: metadata.site_icons,
+            discussion: metadata.discussion,
+            next_page_url: metadata.next_page_url,
+        }
+    }
+
+    /// Run the metadata extractors on the raw document
+    ///
+    /// Returns the metadata, with URLs resolved when a base URL was given, and
+    /// the document base URL (the page URL joined with any `<base href>`).
+    fn extract_metadata(&self) -> (Metadata, Option<String>) {
+        let json_ld = if !self.options.disable_json_ld {
+            get_json_ld(&self.document)
+        } else {
+            Metadata::default()
+        };
+
+        let mut metadata = get_article_metadata(&self.document, json_ld);
+
+        let document_base_url = self
+            .base_url
+            .as_deref()
+            .map(|url| dom_utils::get_document_base_url(&self.document, url));
+        if let (Some(base_url), Some(page_url)) =
+            (document_base_url.as_deref(), self.base_url.as_deref())
+        {
+            resolve_metadata_urls(&mut metadata, base_url, page_url);
+        }
+
+        // Numbered page links are read relative to the page we are on; the canonical
+        // URL is only a fallback, as it is often missing or points at the first page
+        let current_url = self
+            .base_url
+            .as_deref()
+            .or(metadata.canonical_url.as_deref());
+        let next_page_url = pagination::extract_next_page_url(&self.document, current_url);
+        metadata.next_page_url = match document_base_url.as_deref() {
+            Some(base_url) => next_page_url.and_then(|href| {
+                pagination::resolve_next_page_url(
+                    &utils::unescape_html_entities(&href),
+                    base_url,
+                    current_url,
+                )
+            }),
+            None => next_page_url,
+        };
+
+        (metadata, document_base_url)
+    }
+
+    /// Clean extracted content the way [`parse`](Readability::parse) does for
+    /// `Article::content`, before direction marking
+    fn clean_content(&self, content_html: &str, metadata: &Metadata) -> String {
+        let document_base_url = self
+            .base_url
+            .as_deref()
+            .map(|url| dom_utils::get_document_base_url(&self.document, url));
+        let base_url = document_base_url.as_deref();
+        let content_html = cleaner::fix_lazy_images(content_html);
+        let cleaned_wrapper_html = cleaner::clean_article_content_light(&content_html, base_url)
+            .unwrap_or_else(|_| content_html.clone());
+
+        let mut prepped_html = crate::post_processor::prep_article(
+            &cleaned_wrapper_html,
+            self.options.clean_styles,
+            self.options.clean_whitespace,
+            &self.options.semantic_elements,
+        );
+
+        // Remove title from content if the option is enabled
+        if self.options.remove_title_from_content {
+            if let Some(ref title) = metadata.title {
+                prepped_html =
+                    crate::post_processor::remove_title_from_content(&prepped_html, title);
+            }
+        }
+        if self.options.remove_subtitle_from_content {
+            if let Some(ref subtitle) = metadata.subtitle {
+                prepped_html =
+                    crate::post_processor::remove_subtitle_from_content(&prepped_html, subtitle);
+            }
+        }
+        let cleaned_html = match cleaner::clean_article_content(&prepped_html, base_url) {
+            Ok(html) => html,
+            Err(e) => {
+                if self.options.debug {
+                    eprintln!("Error cleaning content: {e}");
+                }
+                prepped_html
+            }
+        };
+        let cleaned_html =
+            cleaner::strip_selected_elements(&cleaned_html, &self.options.strip_selectors);
+        cleaner::collapse_srcset(&cleaned_html, self.options.srcset_selection)
+    }
+
     /// Extract plain text from HTML content
     fn get_text_content(&self, html: &str) -> String {
-        let doc = Html::parse_fragment(html);
-        doc.root_element().text().collect::<String>()
+        dom_utils::content_text(html)
     }
 
     /// Generate an excerpt from the first paragraph of article HTML
@@ -473,4 +730,90 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_text_direction_fallback() {
+        let paragraph =
+            "<p>The council approved the new bridge on Monday, ending years of debate.</p>";
+        let html = format!(
+            "<html><body><article>{}</article></body></html>",
+            paragraph.repeat(10)
+        );
+        let article = Readability::new(&html, None, None)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(article.dir, None);
+
+        let paragraph =
+            "<p>מועצת העיר אישרה ביום שני את בניית הגשר החדש, לאחר שנים של ויכוחים.</p>";
+        let html = format!(
+            "<html><body><article>{}</article></body></html>",
+            paragraph.repeat(10)
+        );
+        let article = Readability::new(&html, None, None)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(article.dir.as_deref(), Some("rtl"));
+        assert!(!article.content.unwrap().contains("dir="));
+    }
+
+    #[test]
+    fn test_next_page_url_from_current_page() {
+        let html = r#"<html><head><link rel="canonical" href="/story"></head><body><article>
+            <h1>A long story</h1>
+            <p>The second page of the story continues where the first one left off, with more detail.</p>
+            <p>It goes on for a while longer before the reader is sent to the third and final page.</p>
+            <div class="pagination"><a href="/story?page=1">1</a> <a href="/story?page=2">2</a>
+            <a href="/story?page=3">3</a></div>
+        </article></body></html>"#;
+
+        let article = Readability::new(html, Some("https://ex.com/story?page=2"), None)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(
+            article.next_page_url.as_deref(),
+            Some("https://ex.com/story?page=3")
+        );
+
+        let first = Readability::new(html, Some("https://ex.com/story"), None)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(
+            first.next_page_url.as_deref(),
+            Some("https://ex.com/story?page=2")
+        );
+    }
+
+    #[test]
+    fn test_metadata_matches_parse() {
+        let html = r#"<html lang="en"><head>
+            <title>Harbour reopens after storm</title>
+            <meta name="author" content="Jane Smith">
+            <meta property="og:site_name" content="Coast News">
+            <meta property="article:published_time" content="2024-03-01T09:00:00Z">
+            <link rel="canonical" href="/harbour">
+        </head><body><article>
+            <h1>Harbour reopens after storm</h1>
+            <p>The harbour reopened on Friday after a week of repairs to the sea wall, the port authority said.</p>
+            <p>Fishing boats returned to their moorings in the afternoon as crews cleared the last of the debris.</p>
+        </article></body></html>"#;
+        let url = Some("https://example.com/news/");
+
+        let metadata = Readability::new(html, url, None).unwrap().metadata();
+        let article = Readability::new(html, url, None).unwrap().parse().unwrap();
+
+        assert_eq!(metadata.title, article.title);
+        assert_eq!(metadata.byline, article.byline);
+        assert_eq!(metadata.site_name, article.site_name);
+        assert_eq!(metadata.published_time, article.published_time);
+        assert_eq!(metadata.lang.as_deref(), Some("en"));
+        assert_eq!(
+            metadata.canonical_url.as_deref(),
+            Some("https://example.com/harbour")
+        );
+    }
 }
@@ -1,5 +1,6 @@
 //! Utility functions for text processing and manipulation.
 
+use crate::byline::{FragmentKind, RemovedFragment};
 use crate::constants::REGEXPS;
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -70,7 +71,23 @@ pub fn is_url(s: &str) -> bool {
     url::Url::parse(s).is_ok()
 }
 
-static BY_PREFIX_REGEX: Lazy<Regex> =
+/// Resolve a possibly relative URL against a base URL
+///
+/// Returns `None` when the base is invalid or the result is not a valid URL.
+pub fn resolve_url(url: &str, base_url: &str) -> Option<String> {
+    let trimmed = url.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    url::Url::parse(base_url)
+        .ok()?
+        .join(trimmed)
+        .ok()
+        .map(|resolved| resolved.to_string())
+}
+
+pub(crate) static BY_PREFIX_REGEX: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"(?i)^(by|par)[\s:,\-–—]+").unwrap());
 
 static SOFT_SPACE_CHARS: &[char] = &['\u{00a0}', '\u{200b}', '\u{feff}'];
@@ -222,7 +239,7 @@ fn split_candidate_segments(text: &str) -> Vec<&str> {
     segments
 }
 
-fn looks_like_datetime_segment(segment: &str) -> bool {
+pub(crate) fn looks_like_datetime_segment(segment: &str) -> bool {
     let lower = segment.trim().to_lowercase();
     if lower.is_empty() {
         return false;
@@ -300,7 +317,7 @@ fn strip_trailing_datetime_clause<'a>(text: &'a str, allow_strip: bool) -> Cow<'
     Cow::Borrowed(text)
 }
 
-fn remove_timestamp_lines(text: &str) -> Option<String> {
+fn remove_timestamp_lines(text: &str, removed: &mut Vec<RemovedFragment>) -> Option<String> {
     let mut changed = false;
     let mut kept = Vec::new();
 
@@ -312,6 +329,7 @@ fn remove_timestamp_lines(text: &str) -> Option<String> {
         }
 
         changed = true;
+        removed.push(RemovedFragment::new(trimmed, FragmentKind::Date));
     }
 
     if !changed {
@@ -321,7 +339,7 @@ fn remove_timestamp_lines(text: &str) -> Option<String> {
     }
 }
 
-fn looks_like_live_timestamp_segment(segment: &str) -> bool {
+pub(crate) fn looks_like_live_timestamp_segment(segment: &str) -> bool {
     let lower = segment.trim().to_lowercase();
     if lower.is_empty() {
         return false;
@@ -397,7 +415,9 @@ fn looks_like_live_timestamp_segment(segment: &str) -> bool {
     false
 }
 
-pub(crate) fn looks_like_org_credit(text: &str) -> bool {
+/// Heuristic check for credits to an organization rather than a person, such as
+/// news agencies ("AFP", "Reuters") or desks ("Our Foreign Staff").
+pub fn looks_like_org_credit(text: &str) -> bool {
     if contains_author_like_segment(text) {
         return false;
     }
@@ -579,6 +599,14 @@ pub enum CleanBylineOutcome {
 }
 
 pub fn clean_byline_text_with_reason(text: &str) -> CleanBylineOutcome {
+    clean_byline_text_tracked(text, &mut Vec::new())
+}
+
+/// [`clean_byline_text_with_reason`], recording the dates and social handles it drops
+pub(crate) fn clean_byline_text_tracked(
+    text: &str,
+    removed: &mut Vec<RemovedFragment>,
+) -> CleanBylineOutcome {
     let trimmed = trim_soft_space(text.trim());
     if trimmed.is_empty() {
         return CleanBylineOutcome::Dropped;
@@ -600,10 +628,17 @@ pub fn clean_byline_text_with_reason(text: &str) -> CleanBylineOutcome {
     canonical = collapse_blank_lines_preserve_indent(&canonical);
 
     let has_author_segment = contains_author_like_segment(&canonical);
-    canonical = strip_trailing_datetime_clause(&canonical, has_author_segment).into_owned();
+    let stripped = strip_trailing_datetime_clause(&canonical, has_author_segment).into_owned();
+    if stripped.len() < canonical.len() {
+        removed.push(RemovedFragment::new(
+            &canonical[stripped.len()..],
+            FragmentKind::Date,
+        ));
+    }
+    canonical = stripped;
 
     if has_author_segment {
-        if let Some(filtered) = remove_timestamp_lines(&canonical) {
+        if let Some(filtered) = remove_timestamp_lines(&canonical, removed) {
             if filtered.trim().is_empty() {
                 return CleanBylineOutcome::Dropped;
             }
@@ -611,7 +646,7 @@ pub fn clean_byline_text_with_reason(text: &str) -> CleanBylineOutcome {
         }
     }
 
-    if let Some(filtered) = remove_social_handle_lines(&canonical) {
+    if let Some(filtered) = remove_social_handle_lines(&canonical, removed) {
         if filtered.trim().is_empty() {
             return CleanBylineOutcome::Dropped;
         }
@@ -654,6 +689,468 @@ pub fn clean_byline_text(text: &str) -> Option<String> {
     }
 }
 
+/// Byline prefixes and date vocabulary for one language.
+struct BylineLocale {
+    /// Primary language subtags (`de` for `de-AT`, ...)
+    langs: &'static [&'static str],
+    /// Credit prefixes such as "Von" or "文/", matched case-insensitively
+    prefixes: &'static [&'static str],
+    /// Prefixes that also start surnames ("Di Maio"): only stripped when written
+    /// in lowercase or followed by a colon or dash
+    particles: &'static [&'static str],
+    /// Month names and abbreviations, matched as whole words
+    months: &'static [&'static str],
+    /// Phrases that mark a date clause on their own ("aktualisiert")
+    updated: &'static [&'static str],
+    /// Phrases that mark a relative date when a number is present ("vor 5 Minuten")
+    relative: &'static [&'static str],
+    /// Whether the prefix may run straight into the name (CJK scripts)
+    unspaced: bool,
+}
+
+static BYLINE_LOCALES: &[BylineLocale] = &[
+    BylineLocale {
+        langs: &["de"],
+        prefixes: &["von"],
+        particles: &[],
+        months: &[
+            "januar",
+            "jan",
+            "februar",
+            "feb",
+            "märz",
+            "mär",
+            "april",
+            "apr",
+            "mai",
+            "juni",
+            "jun",
+            "juli",
+            "jul",
+            "august",
+            "aug",
+            "september",
+            "sep",
+            "sept",
+            "oktober",
+            "okt",
+            "november",
+            "nov",
+            "dezember",
+            "dez",
+        ],
+        updated: &["aktualisiert", "veröffentlicht", "gestern", "heute"],
+        relative: &["vor", "uhr", "min", "std"],
+        unspaced: false,
+    },
+    BylineLocale {
+        langs: &["fr"],
+        prefixes: &["par"],
+        particles: &[],
+        months: &[
+            "janvier",
+            "janv",
+            "février",
+            "févr",
+            "mars",
+            "avril",
+            "avr",
+            "mai",
+            "juin",
+            "juillet",
+            "juil",
+            "août",
+            "septembre",
+            "sept",
+            "octobre",
+            "oct",
+            "novembre",
+            "nov",
+            "décembre",
+            "déc",
+        ],
+        updated: &["mis à jour", "publié", "hier", "aujourd'hui"],
+        relative: &["il y a", "min", "h"],
+        unspaced: false,
+    },
+    BylineLocale {
+        langs: &["es"],
+        prefixes: &["por"],
+        particles: &[],
+        months: &[
+            "enero",
+            "ene",
+            "febrero",
+            "feb",
+            "marzo",
+            "mar",
+            "abril",
+            "abr",
+            "mayo",
+            "may",
+            "junio",
+            "jun",
+            "julio",
+            "jul",
+            "agosto",
+            "ago",
+            "septiembre",
+            "setiembre",
+            "sep",
+            "sept",
+            "octubre",
+            "oct",
+            "noviembre",
+            "nov",
+            "diciembre",
+            "dic",
+        ],
+        updated: &["actualizado", "publicado", "ayer", "hoy"],
+        relative: &["hace", "min", "h"],
+        unspaced: false,
+    },
+    BylineLocale {
+        langs: &["pt"],
+        prefixes: &["por"],
+        particles: &[],
+        months: &[
+            "janeiro",
+            "jan",
+            "fevereiro",
+            "fev",
+            "março",
+            "mar",
+            "abril",
+            "abr",
+            "maio",
+            "mai",
+            "junho",
+            "jun",
+            "julho",
+            "jul",
+            "agosto",
+            "ago",
+            "setembro",
+            "set",
+            "outubro",
+            "out",
+            "novembro",
+            "nov",
+            "dezembro",
+            "dez",
+        ],
+        updated: &[
+            "atualizado",
+            "atualizada",
+            "publicado",
+            "publicada",
+            "ontem",
+            "hoje",
+        ],
+        relative: &["há", "min", "h"],
+        unspaced: false,
+    },
+    BylineLocale {
+        langs: &["it"],
+        prefixes: &["a cura di", "di"],
+        particles: &["di"],
+        months: &[
+            "gennaio",
+            "gen",
+            "febbraio",
+            "feb",
+            "marzo",
+            "mar",
+            "aprile",
+            "apr",
+            "maggio",
+            "mag",
+            "giugno",
+            "giu",
+            "luglio",
+            "lug",
+            "agosto",
+            "ago",
+            "settembre",
+            "set",
+            "ottobre",
+            "ott",
+            "novembre",
+            "nov",
+            "dicembre",
+            "dic",
+        ],
+        updated: &["aggiornato", "pubblicato", "ieri", "oggi"],
+        relative: &["fa", "min", "ore"],
+        unspaced: false,
+    },
+    BylineLocale {
+        langs: &["nl"],
+        prefixes: &["door"],
+        particles: &[],
+        months: &[
+            "januari",
+            "jan",
+            "februari",
+            "feb",
+            "maart",
+            "mrt",
+            "april",
+            "apr",
+            "mei",
+            "juni",
+            "jun",
+            "juli",
+            "jul",
+            "augustus",
+            "aug",
+            "september",
+            "sep",
+            "sept",
+            "oktober",
+            "okt",
+            "november",
+            "nov",
+            "december",
+            "dec",
+        ],
+        updated: &["bijgewerkt", "gepubliceerd", "gisteren", "vandaag"],
+        relative: &["geleden", "min", "uur"],
+        unspaced: false,
+    },
+    BylineLocale {
+        langs: &["ru"],
+        prefixes: &["автор", "текст"],
+        particles: &[],
+        months: &[
+            "января",
+            "февраля",
+            "марта",
+            "апреля",
+            "мая",
+            "июня",
+            "июля",
+            "августа",
+            "сентября",
+            "октября",
+            "ноября",
+            "декабря",
+            "янв",
+            "фев",
+            "мар",
+            "апр",
+            "июн",
+            "июл",
+            "авг",
+            "сен",
+            "окт",
+            "ноя",
+            "дек",
+        ],
+        updated: &["обновлено", "опубликовано", "вчера", "сегодня"],
+        relative: &["назад", "мин", "ч"],
+        unspaced: false,
+    },
+    BylineLocale {
+        langs: &["ja"],
+        prefixes: &["文/", "文／", "文・", "記者", "著者", "執筆"],
+        particles: &[],
+        months: &[],
+        updated: &["更新", "公開", "配信"],
+        relative: &["前"],
+        unspaced: true,
+    },
+    BylineLocale {
+        langs: &["zh"],
+        prefixes: &["文/", "文／", "文|", "文｜", "记者", "記者", "作者"],
+        particles: &[],
+        months: &[],
+        updated: &["更新", "发布", "發布", "发表", "發表"],
+        relative: &["前"],
+        unspaced: true,
+    },
+];
+
+/// Numeric dates and times: `12.03.2024`, `2024-03-12`, `14:30`, `14h30`, `2024年3月12日`
+static LOCALIZED_DATE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"\d{1,4}[./\-]\d{1,2}[./\-]\d{1,4}|\b\d{1,2}[:h]\d{2}\b|\d+\s*年\s*\d+\s*月|\d+\s*月\s*\d+\s*日",
+    )
+    .unwrap()
+});
+
+/// Look up the byline vocabulary for a BCP 47 language tag such as `de-DE`
+fn byline_locale(lang: Option<&str>) -> Option<&'static BylineLocale> {
+    let primary = lang?.trim().split(['-', '_']).next()?.to_ascii_lowercase();
+    BYLINE_LOCALES
+        .iter()
+        .find(|locale| locale.langs.contains(&primary.as_str()))
+}
+
+/// Split a leading credit prefix ("Von", "Автор:", "文/") off a byline
+fn strip_localized_prefix<'a>(text: &'a str, locale: &BylineLocale) -> Option<&'a str> {
+    let lower = text.to_lowercase();
+    // Lowercasing can change byte lengths, so only trust prefixes that keep them
+    if lower.len() != text.len() {
+        return None;
+    }
+
+    locale.prefixes.iter().find_map(|prefix| {
+        if !lower.starts_with(prefix) {
+            return None;
+        }
+        let rest = &text[prefix.len()..];
+        let after_separator = rest
+            .trim_start_matches(|c: char| c.is_whitespace() || SOFT_SPACE_CHARS.contains(&c))
+            .trim_start_matches([':', '：', '/', '／', '|', '｜', '・', '-', '–', '—'])
+            .trim_start();
+        let separated = after_separator.len() < rest.len();
+        if !separated && !locale.unspaced {
+            return None;
+        }
+        if locale.particles.contains(prefix)
+            && !text.starts_with(prefix)
+            && !rest.trim_start().starts_with([':', '：', '-', '–', '—'])
+        {
+            return None;
+        }
+
+        let first = after_separator.chars().next()?;
+        let starts_name = if locale.unspaced {
+            first.is_alphabetic()
+        } else {
+            first.is_uppercase()
+        };
+        starts_name.then_some(after_separator)
+    })
+}
+
+/// Whether a byline segment is a date, time or "updated" clause in the locale's language
+fn looks_like_localized_datetime_segment(segment: &str, locale: &BylineLocale) -> bool {
+    let lower = segment.trim().to_lowercase();
+    if lower.is_empty() {
+        return false;
+    }
+    if LOCALIZED_DATE_REGEX.is_match(&lower) {
+        return true;
+    }
+
+    let words: Vec<&str> = lower
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .filter(|word| !word.is_empty())
+        .collect();
+    let has_phrase = |phrase: &str| {
+        if locale.unspaced {
+            return lower.contains(phrase);
+        }
+        let phrase_words: Vec<&str> = phrase.split_whitespace().collect();
+        words
+            .windows(phrase_words.len())
+            .any(|window| window == phrase_words.as_slice())
+    };
+
+    if locale.updated.iter().any(|phrase| has_phrase(phrase)) {
+        return true;
+    }
+
+    let has_digit = lower.chars().any(|c| c.is_ascii_digit());
+    has_digit
+        && (locale.months.iter().any(|month| words.contains(month))
+            || locale.relative.iter().any(|phrase| has_phrase(phrase)))
+}
+
+/// Remove date clauses after a separator at the end of a line, possibly several
+fn strip_localized_datetime_tail(
+    line: &str,
+    locale: &BylineLocale,
+    removed: &mut Vec<RemovedFragment>,
+) -> String {
+    let mut line = line.trim_end().to_string();
+    loop {
+        let tail = [" | ", " - ", " – ", " — ", " · ", ", ", "，", "　", " "]
+            .iter()
+            .filter_map(|separator| {
+                line.rfind(separator)
+                    .map(|idx| (idx, idx + separator.len()))
+            })
+            .filter(|(_, tail_start)| {
+                looks_like_localized_datetime_segment(&line[*tail_start..], locale)
+            })
+            .map(|(idx, _)| idx)
+            .min();
+        match tail {
+            Some(idx) if !line[..idx].trim().is_empty() => {
+                removed.push(RemovedFragment::new(&line[idx..], FragmentKind::Date));
+                line = line[..idx].trim_end().to_string()
+            }
+            _ => return line,
+        }
+    }
+}
+
+/// Returns true if the text looks like a byline in English or in the page language.
+pub fn looks_like_localized_byline(text: &str, lang: Option<&str>) -> bool {
+    if looks_like_byline(text) {
+        return true;
+    }
+    let Some(locale) = byline_locale(lang) else {
+        return false;
+    };
+    strip_localized_prefix(trim_soft_space(text.trim()), locale).is_some()
+}
+
+/// Remove localized credit prefixes and date clauses from a cleaned byline.
+///
+/// `lang` is the page language (e.g. `Metadata::lang`). Bylines in languages
+/// without a vocabulary table, including English, are returned unchanged: the
+/// English "By" prefix is kept, matching Mozilla's output. Returns `None` when
+/// nothing but a date is left.
+pub fn clean_localized_byline(text: &str, lang: Option<&str>) -> Option<String> {
+    clean_localized_byline_tracked(text, lang, &mut Vec::new())
+}
+
+/// [`clean_localized_byline`], recording the prefixes and dates it drops
+pub(crate) fn clean_localized_byline_tracked(
+    text: &str,
+    lang: Option<&str>,
+    removed: &mut Vec<RemovedFragment>,
+) -> Option<String> {
+    let Some(locale) = byline_locale(lang) else {
+        return Some(text.to_string());
+    };
+
+    // Strip trailing date clauses from every line, then drop lines that are only dates
+    let mut lines: Vec<String> = Vec::new();
+    for line in text.split('\n') {
+        let line = strip_localized_datetime_tail(line, locale, removed);
+        if looks_like_localized_datetime_segment(&line, locale) {
+            removed.push(RemovedFragment::new(&line, FragmentKind::Date));
+        } else {
+            lines.push(line);
+        }
+    }
+    let mut cleaned = lines.join("\n").trim().to_string();
+
+    if let Some(rest) = strip_localized_prefix(&cleaned, locale) {
+        removed.push(RemovedFragment::new(
+            &cleaned[..cleaned.len() - rest.len()],
+            FragmentKind::Prefix,
+        ));
+        cleaned = rest.to_string();
+    }
+
+    let cleaned = cleaned
+        .trim()
+        .trim_end_matches(['-', '–', '—', '|', '•', ':', ';', ','])
+        .trim();
+    if cleaned.is_empty() {
+        return None;
+    }
+    Some(cleaned.to_string())
+}
+
+/// Returns true if the byline only repeats a credit inside the site name,
+/// e.g. "Joe Wee" on a site named "SIMPLYFOUND.COM | BY: Joe Wee".
 pub fn is_byline_redundant_with_site_name(byline: &str, site_name: &str) -> bool {
     let normalized_byline = normalize_whitespace(byline).to_lowercase();
     if normalized_byline.len() < 3 {
@@ -708,13 +1205,17 @@ fn collapse_blank_lines_preserve_indent(text: &str) -> String {
     result
 }
 
-fn remove_social_handle_lines(text: &str) -> Option<String> {
+fn remove_social_handle_lines(text: &str, removed: &mut Vec<RemovedFragment>) -> Option<String> {
     let mut changed = false;
     let mut kept = Vec::new();
 
     for line in text.split('\n') {
         if looks_like_social_handle(line) {
             changed = true;
+            removed.push(RemovedFragment::new(
+                line.trim(),
+                FragmentKind::SocialHandle,
+            ));
             continue;
         }
         kept.push(line);
@@ -743,6 +1244,26 @@ mod tests {
         assert_eq!(normalize_whitespace("a  b  c"), "a b c");
     }
 
+    #[test]
+    fn test_resolve_url() {
+        let base = "https://example.com/news/story.html";
+        assert_eq!(
+            resolve_url("/img/a.jpg", base).as_deref(),
+            Some("https://example.com/img/a.jpg")
+        );
+        assert_eq!(
+            resolve_url("b.jpg", base).as_deref(),
+            Some("https://example.com/news/b.jpg")
+        );
+        assert_eq!(
+            resolve_url("//cdn.example.com/a.jpg", base).as_deref(),
+            Some("https://cdn.example.com/a.jpg")
+        );
+        assert_eq!(resolve_url("https://[bad", base), None);
+        assert_eq!(resolve_url("  ", base), None);
+        assert_eq!(resolve_url("a.jpg", "not a url"), None);
+    }
+
     #[test]
     fn test_looks_like_byline() {
         assert!(looks_like_byline("By Alice Smith"));
@@ -850,4 +1371,85 @@ mod tests {
         assert!(!looks_like_dateline("By Erin Cunningham"));
         assert!(!looks_like_dateline("Washington Post Staff"));
     }
+
+    #[test]
+    fn test_clean_localized_byline() {
+        assert_eq!(
+            clean_localized_byline("Von Max Mustermann | 12.03.2024, 14:30 Uhr", Some("de-DE"))
+                .as_deref(),
+            Some("Max Mustermann")
+        );
+        assert_eq!(
+            clean_localized_byline("Par Jean Dupont\nMis à jour le 3 mars 2024", Some("fr"))
+                .as_deref(),
+            Some("Jean Dupont")
+        );
+        assert_eq!(
+            clean_localized_byline("Por María López, hace 5 min", Some("es")).as_deref(),
+            Some("María López")
+        );
+        assert_eq!(
+            clean_localized_byline("Автор: Иван Петров, 12 марта 2024", Some("ru")).as_deref(),
+            Some("Иван Петров")
+        );
+        assert_eq!(
+            clean_localized_byline("文/山田太郎 2024年3月12日", Some("ja")).as_deref(),
+            Some("山田太郎")
+        );
+        // Names that merely start like a prefix are left alone
+        assert_eq!(
+            clean_localized_byline("Diana Rossi", Some("it")).as_deref(),
+            Some("Diana Rossi")
+        );
+        // "Di" also starts Italian surnames, so it is only a prefix in lowercase or before a colon
+        assert_eq!(
+            clean_localized_byline("Di Maio Luigi", Some("it")).as_deref(),
+            Some("Di Maio Luigi")
+        );
+        assert_eq!(
+            clean_localized_byline("Di Stefano Marco", Some("it-IT")).as_deref(),
+            Some("Di Stefano Marco")
+        );
+        assert_eq!(
+            clean_localized_byline("di Marco Rossi", Some("it")).as_deref(),
+            Some("Marco Rossi")
+        );
+        assert_eq!(
+            clean_localized_byline("Di: Marco Rossi", Some("it")).as_deref(),
+            Some("Marco Rossi")
+        );
+        assert_eq!(
+            clean_localized_byline("A cura di Marco Rossi", Some("it")).as_deref(),
+            Some("Marco Rossi")
+        );
+        // English keeps its "By" prefix
+        assert_eq!(
+            clean_localized_byline("By Alice Smith", Some("en")).as_deref(),
+            Some("By Alice Smith")
+        );
+        assert_eq!(
+            clean_localized_byline("Aktualisiert am 12.03.2024", Some("de")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_looks_like_localized_byline() {
+        assert!(looks_like_localized_byline(
+            "Von Max Mustermann",
+            Some("de")
+        ));
+        assert!(looks_like_localized_byline("Door Jan de Vries", Some("nl")));
+        assert!(!looks_like_localized_byline(
+            "Door Jan de Vries",
+            Some("en")
+        ));
+        assert!(!looks_like_localized_byline(
+            "von der Leyen sagte",
+            Some("de")
+        ));
+        assert!(!looks_like_localized_byline("Di Maio Luigi", Some("it")));
+        assert!(!looks_like_localized_byline("Di Stefano Marco", Some("it")));
+        assert!(looks_like_localized_byline("di Marco Rossi", Some("it")));
+    }
 }